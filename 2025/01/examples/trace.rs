@@ -0,0 +1,21 @@
+//! Writes every rotation of the dial as CSV, with where it left the dial and
+//! the running totals, for plotting or checking by hand:
+//!
+//! ```bash
+//! cargo run -q -p aoc-2025-01 --example trace -- path/to/input.txt > trace.csv
+//! ```
+//!
+//! Without a path, the committed input is used.
+
+use std::error::Error;
+
+use aoc_common::input::Inputs;
+use aoc_common::solution::Params;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = Inputs::from_args().load(2025, 1, aoc_2025_01::ACTUAL)?;
+    let rotations = aoc_2025_01::parse(input.text.trim())?;
+    let dial = aoc_2025_01::dial(&Params::new())?;
+    print!("{}", dial.trace(&rotations).to_csv()?);
+    Ok(())
+}
@@ -0,0 +1,438 @@
+//! A circular dial which rotations turn a click at a time, counting how often
+//! it points at zero without stepping through every click.
+
+use aoc_common::error::{Error, Line, Result};
+
+/// Which way a rotation turns the dial: left towards lower numbers, right
+/// towards higher ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// One line of the input, e.g. `L68`. The direction is only checked against
+/// a [`Dial`]'s alphabet when the rotation is applied to it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rotation {
+    /// The line the rotation was read from, for reporting errors.
+    pub line: usize,
+    pub direction: char,
+    pub distance: u64,
+}
+
+impl Rotation {
+    pub fn parse(line: Line) -> Result<Self> {
+        let mut chars = line.text.chars();
+        let direction = chars
+            .next()
+            .ok_or_else(|| line.error_at_end("expected a rotation such as `L68`"))?;
+        let distance = line.parse::<u64>(chars.as_str())?;
+        Ok(Rotation {
+            line: line.number,
+            direction,
+            distance,
+        })
+    }
+}
+
+/// Where a rotation left the dial, and how often it pointed at zero on the
+/// way.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DialResult {
+    pub new_position: u64,
+    /// Counts the number of times zero was _passed_ during the rotation.
+    pub zero_passes: u64,
+    /// Every click which left the dial at zero, which is `zero_passes` plus
+    /// the last click if the dial ended up there. A rotation of no clicks
+    /// has no hits, even at zero.
+    pub zero_hits: u64,
+}
+
+/// A dial numbered from `0` to `size - 1`, pointing at `position`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    /// The characters which turn the dial left and right.
+    alphabet: [char; 2],
+}
+
+impl Dial {
+    /// A dial of `size` positions starting at `start`, turned by `L` and `R`.
+    /// Errors are named after the params which configure it.
+    pub fn new(size: u64, start: u64) -> Result<Self> {
+        if size == 0 {
+            return Err(Error::param(
+                "size",
+                size,
+                "the dial needs at least one position",
+            ));
+        }
+        if start >= size {
+            return Err(Error::param(
+                "start",
+                start,
+                format!("the dial only goes up to {}", size - 1),
+            ));
+        }
+        Ok(Dial {
+            size,
+            position: start,
+            alphabet: ['L', 'R'],
+        })
+    }
+
+    /// Uses `left` and `right` as the directions instead of `L` and `R`.
+    pub fn with_directions(mut self, left: char, right: char) -> Result<Self> {
+        if left == right {
+            return Err(Error::param(
+                "directions",
+                format!("{left}{right}"),
+                "left and right need different characters",
+            ));
+        }
+        self.alphabet = [left, right];
+        Ok(self)
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The direction `c` stands for, if it is in the dial's alphabet.
+    pub fn direction(&self, c: char) -> Option<Direction> {
+        match self.alphabet {
+            [left, _] if c == left => Some(Direction::Left),
+            [_, right] if c == right => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Turns the dial `distance` clicks, in constant time however far that
+    /// is.
+    pub fn turn(&mut self, direction: Direction, distance: u64) -> DialResult {
+        let (size, position) = (u128::from(self.size), u128::from(self.position));
+        let distance = u128::from(distance);
+        let (new_position, zero_hits) = match direction {
+            Direction::Right => ((position + distance) % size, (position + distance) / size),
+            Direction::Left => {
+                let new_position = (position + size - distance % size) % size;
+                // The first zero is `position` clicks away, or a full turn
+                // away when starting on it.
+                let first = if position == 0 { size } else { position };
+                let hits = if distance < first {
+                    0
+                } else {
+                    1 + (distance - first) / size
+                };
+                (new_position, hits)
+            }
+        };
+        // Both fit: the position is below the size, and there can't be more
+        // hits than clicks.
+        let new_position = u64::try_from(new_position).expect("position fits the dial");
+        let zero_hits = u64::try_from(zero_hits).expect("hits are at most the distance");
+
+        self.position = new_position;
+        let ends_on_zero = new_position == 0 && distance > 0;
+        DialResult {
+            new_position,
+            zero_passes: zero_hits - u64::from(ends_on_zero),
+            zero_hits,
+        }
+    }
+
+    /// Applies `rotation`, failing if its direction isn't one of the dial's.
+    pub fn rotate(&mut self, rotation: &Rotation) -> Result<DialResult> {
+        let direction = self.direction(rotation.direction).ok_or_else(|| {
+            let [left, right] = self.alphabet;
+            Error::parse(
+                rotation.line,
+                1,
+                format!(
+                    "unknown direction `{}`, expected `{left}` or `{right}`",
+                    rotation.direction
+                ),
+            )
+        })?;
+        Ok(self.turn(direction, rotation.distance))
+    }
+
+    /// Applies each of `rotations` in turn, one step at a time.
+    pub fn trace(self, rotations: &[Rotation]) -> Trace<'_> {
+        Trace {
+            dial: self,
+            rotations: rotations.iter(),
+            total_landings: 0,
+            total_zero_hits: Some(0),
+        }
+    }
+}
+
+/// One rotation of a [`Trace`], along with the running totals up to and
+/// including it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step<'a> {
+    pub rotation: &'a Rotation,
+    pub result: DialResult,
+    /// The rotations so far which left the dial at zero, part one's answer.
+    pub total_landings: u64,
+    /// The clicks so far which left the dial at zero, part two's answer, or
+    /// `None` once there are too many to count.
+    pub total_zero_hits: Option<u64>,
+}
+
+/// Turns a dial by each rotation in turn, yielding every [`Step`]. Stops
+/// after the first error.
+pub struct Trace<'a> {
+    dial: Dial,
+    rotations: std::slice::Iter<'a, Rotation>,
+    total_landings: u64,
+    total_zero_hits: Option<u64>,
+}
+
+impl<'a> Trace<'a> {
+    fn step(&mut self, rotation: &'a Rotation) -> Result<Step<'a>> {
+        let result = self.dial.rotate(rotation)?;
+        self.total_landings += u64::from(result.new_position == 0);
+        self.total_zero_hits = self
+            .total_zero_hits
+            .and_then(|total| total.checked_add(result.zero_hits));
+        Ok(Step {
+            rotation,
+            result,
+            total_landings: self.total_landings,
+            total_zero_hits: self.total_zero_hits,
+        })
+    }
+
+    /// Writes the steps as CSV, one row per rotation after a header. A total
+    /// which has overflowed is left empty.
+    pub fn to_csv(self) -> Result<String> {
+        let mut csv = String::from(
+            "line,direction,distance,position,zero_passes,zero_hits,total_landings,total_zero_hits\n",
+        );
+        for step in self {
+            let Step {
+                rotation,
+                result,
+                total_landings,
+                total_zero_hits,
+            } = step?;
+            let total_zero_hits = total_zero_hits.map_or(String::new(), |t| t.to_string());
+            let direction = match rotation.direction {
+                c @ (',' | '"') => format!("\"{}\"", c.to_string().replace('"', "\"\"")),
+                c => c.to_string(),
+            };
+            csv.push_str(&format!(
+                "{},{direction},{},{},{},{},{total_landings},{total_zero_hits}\n",
+                rotation.line,
+                rotation.distance,
+                result.new_position,
+                result.zero_passes,
+                result.zero_hits,
+            ));
+        }
+        Ok(csv)
+    }
+}
+
+impl<'a> Iterator for Trace<'a> {
+    type Item = Result<Step<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rotation = self.rotations.next()?;
+        let step = self.step(rotation);
+        if step.is_err() {
+            self.rotations = [].iter();
+        }
+        Some(step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotation(text: &str) -> Rotation {
+        Rotation::parse(Line::new(1, text)).unwrap()
+    }
+
+    /// Turns the dial one click at a time.
+    fn turn_slowly(dial: &mut Dial, direction: Direction, distance: u64) -> DialResult {
+        let mut zero_hits = 0;
+        for _ in 0..distance {
+            dial.position = match direction {
+                Direction::Right => (dial.position + 1) % dial.size,
+                Direction::Left => (dial.position + dial.size - 1) % dial.size,
+            };
+            if dial.position == 0 {
+                zero_hits += 1;
+            }
+        }
+        let ends_on_zero = dial.position == 0 && distance > 0;
+        DialResult {
+            new_position: dial.position,
+            zero_passes: zero_hits - u64::from(ends_on_zero),
+            zero_hits,
+        }
+    }
+
+    #[test]
+    fn test_turn_matches_clicking() {
+        for size in 1..=7 {
+            for start in 0..size {
+                for distance in 0..=3 * size + 1 {
+                    for direction in [Direction::Left, Direction::Right] {
+                        let mut fast = Dial::new(size, start).unwrap();
+                        let mut slow = fast.clone();
+                        assert_eq!(
+                            fast.turn(direction, distance),
+                            turn_slowly(&mut slow, direction, distance),
+                            "size {size}, start {start}, {direction:?} {distance}"
+                        );
+                        assert_eq!(fast, slow);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut dial = Dial::new(100, 50).unwrap();
+        let expected = [
+            ("L68", 82, 1),
+            ("L30", 52, 0),
+            ("R48", 0, 0),
+            ("L5", 95, 0),
+            ("R60", 55, 1),
+            ("L55", 0, 0),
+            ("L1", 99, 0),
+            ("L99", 0, 0),
+            ("R14", 14, 0),
+            ("L82", 32, 1),
+        ];
+        for (text, new_position, zero_passes) in expected {
+            let result = dial.rotate(&rotation(text)).unwrap();
+            assert_eq!(
+                (result.new_position, result.zero_passes),
+                (new_position, zero_passes),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_turn_large_distances() {
+        let mut dial = Dial::new(100, 50).unwrap();
+        let result = dial.turn(Direction::Right, u64::MAX);
+        assert_eq!(
+            (result.new_position, result.zero_hits),
+            (65, u64::MAX / 100)
+        );
+
+        let mut dial = Dial::new(u64::MAX, u64::MAX - 1).unwrap();
+        let result = dial.turn(Direction::Right, u64::MAX);
+        assert_eq!((result.new_position, result.zero_hits), (u64::MAX - 1, 1));
+        let result = dial.turn(Direction::Left, u64::MAX);
+        assert_eq!((result.new_position, result.zero_hits), (u64::MAX - 1, 1));
+    }
+
+    #[test]
+    fn test_directions() {
+        let mut dial = Dial::new(10, 0).unwrap().with_directions('-', '+').unwrap();
+        assert_eq!(dial.rotate(&rotation("+3")).unwrap().new_position, 3);
+        assert_eq!(dial.rotate(&rotation("-5")).unwrap().new_position, 8);
+        assert_eq!(
+            dial.rotate(&rotation("R5")).unwrap_err(),
+            Error::parse(1, 1, "unknown direction `R`, expected `-` or `+`")
+        );
+        assert_eq!(dial.position(), 8);
+
+        assert!(Dial::new(10, 0).unwrap().with_directions('L', 'L').is_err());
+    }
+
+    #[test]
+    fn test_new_errors() {
+        assert_eq!(
+            Dial::new(0, 0).unwrap_err().to_string(),
+            "invalid value `0` for parameter `size`: the dial needs at least one position"
+        );
+        assert_eq!(
+            Dial::new(100, 100).unwrap_err().to_string(),
+            "invalid value `100` for parameter `start`: the dial only goes up to 99"
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let rotations: Vec<Rotation> = ["L68", "L30", "R48", "L5", "R60", "L55"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| Rotation::parse(Line::new(i + 1, text)).unwrap())
+            .collect();
+        let steps: Vec<Step> = Dial::new(100, 50)
+            .unwrap()
+            .trace(&rotations)
+            .collect::<Result<_>>()
+            .unwrap();
+        let totals: Vec<(u64, u64, Option<u64>)> = steps
+            .iter()
+            .map(|s| (s.result.new_position, s.total_landings, s.total_zero_hits))
+            .collect();
+        assert_eq!(
+            totals,
+            [
+                (82, 0, Some(1)),
+                (52, 0, Some(1)),
+                (0, 1, Some(2)),
+                (95, 1, Some(2)),
+                (55, 1, Some(3)),
+                (0, 2, Some(4))
+            ]
+        );
+        assert_eq!(steps[2].rotation, &rotations[2]);
+    }
+
+    #[test]
+    fn test_trace_stops_at_an_error() {
+        let rotations = [rotation("L5"), rotation("X5"), rotation("R5")];
+        let mut trace = Dial::new(100, 50).unwrap().trace(&rotations);
+        assert!(trace.next().unwrap().is_ok());
+        assert!(trace.next().unwrap().is_err());
+        assert!(trace.next().is_none());
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let rotations = [rotation("R60"), rotation(",210")];
+        let dial = Dial::new(100, 50)
+            .unwrap()
+            .with_directions(',', 'R')
+            .unwrap();
+        assert_eq!(
+            dial.trace(&rotations).to_csv().unwrap(),
+            "line,direction,distance,position,zero_passes,zero_hits,total_landings,total_zero_hits\n\
+             1,R,60,10,1,1,0,1\n\
+             1,\",\",210,0,2,3,1,4\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Rotation::parse(Line::new(4, "")).err(),
+            Some(Error::parse(4, 1, "expected a rotation such as `L68`"))
+        );
+        assert_eq!(
+            Rotation::parse(Line::new(4, "R")).err(),
+            Some(Error::parse(4, 2, "expected a number"))
+        );
+    }
+}
@@ -0,0 +1,134 @@
+mod dial;
+
+pub use dial::{Dial, DialResult, Direction, Rotation, Step, Trace};
+
+use aoc_common::error::{Error, Result, lines};
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<Rotation>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Rotation>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Vec<Rotation>, params: &Params) -> Result<u64> {
+        part_one(input, dial(params)?)
+    }
+
+    fn part_two(input: &Vec<Rotation>, params: &Params) -> Result<u64> {
+        part_two(input, dial(params)?)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// The dial described by the params: `size` positions (100 by default),
+/// starting at `start` (50), and turned by the two characters of
+/// `directions` (`LR`), left then right.
+pub fn dial(params: &Params) -> Result<Dial> {
+    let dial = Dial::new(params.get_or("size", 100)?, params.get_or("start", 50)?)?;
+    let directions = params.get_or("directions", "LR".to_string())?;
+    match directions.chars().collect::<Vec<_>>()[..] {
+        [left, right] => dial.with_directions(left, right),
+        _ => Err(Error::param(
+            "directions",
+            directions,
+            "expected two characters, for left and right",
+        )),
+    }
+}
+
+pub fn parse(file: &str) -> Result<Vec<Rotation>> {
+    lines(file).map(Rotation::parse).collect()
+}
+
+/// Counts the rotations which leave the dial at zero.
+pub fn part_one(rotations: &[Rotation], dial: Dial) -> Result<u64> {
+    let mut zero_count = 0;
+    for step in dial.trace(rotations) {
+        zero_count = step?.total_landings;
+    }
+
+    Ok(zero_count)
+}
+
+/// Counts every click which leaves the dial at zero.
+pub fn part_two(rotations: &[Rotation], dial: Dial) -> Result<u64> {
+    let mut zero_count = 0;
+    for step in dial.trace(rotations) {
+        zero_count = step?
+            .total_zero_hits
+            .ok_or_else(|| Error::overflow("the number of zeros"))?;
+    }
+
+    Ok(zero_count)
+}
+
+/// Random rotations, `size` of them, of up to a few turns of the dial.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = rng.pick(&['L', 'R']);
+            format!("{direction}{}\n", rng.between(1, 999))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dial_params() {
+        let rotations = parse("+3\n-13\n+20").unwrap();
+        let params = Params::new()
+            .with("size", 10)
+            .with("start", 0)
+            .with("directions", "-+");
+        assert_eq!(part_one(&rotations, dial(&params).unwrap()), Ok(2));
+        assert_eq!(part_two(&rotations, dial(&params).unwrap()), Ok(4));
+
+        let error = part_one(&rotations, dial(&Params::new()).unwrap()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: unknown direction `+`, expected `L` or `R`"
+        );
+        assert!(dial(&Params::new().with("directions", "L")).is_err());
+        assert!(dial(&Params::new().with("size", 0)).is_err());
+    }
+
+    #[test]
+    fn test_part_one_ignores_overflowing_zero_hits() {
+        let rotations = parse(&format!("L50\n{}", "R18446744073709551600\n".repeat(101))).unwrap();
+        let dial = dial(&Params::new()).unwrap();
+        assert_eq!(part_one(&rotations, dial.clone()), Ok(102));
+        assert_eq!(
+            part_two(&rotations, dial),
+            Err(Error::overflow("the number of zeros"))
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("L68\nR1x").err().unwrap().to_string(),
+            "line 2, column 2: invalid number `1x`: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use aoc_common::error::{Error, Result};
+use aoc_common::random::Rng;
+use aoc_common::range::Range;
+use aoc_common::solution::{Params, Solution};
+
+mod pattern;
+
+pub use pattern::{Ids, Pattern, Tally};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<Range>;
+    type PartOne = u128;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Vec<Range>> {
+        parse_ranges(input)
+    }
+
+    fn part_one(input: &Vec<Range>, params: &Params) -> Result<u128> {
+        sum_invalid(input, &pattern(params, 2, Some(2))?)
+    }
+
+    fn part_two(input: &Vec<Range>, params: &Params) -> Result<u128> {
+        sum_invalid(input, &pattern(params, 2, None)?)
+    }
+
+    fn reference_part_one(input: &Vec<Range>, params: &Params) -> Option<Result<u128>> {
+        Some(pattern(params, 2, Some(2)).and_then(|pattern| reference_scan(input, &pattern)))
+    }
+
+    fn reference_part_two(input: &Vec<Range>, params: &Params) -> Option<Result<u128>> {
+        Some(pattern(params, 2, None).and_then(|pattern| reference_scan(input, &pattern)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// Parses ID ranges separated by commas or newlines, e.g. `11-22,95-115`.
+pub fn parse_ranges(file: &str) -> Result<Vec<Range>> {
+    Ok(Range::parse_list(file)?)
+}
+
+/// The pattern of invalid IDs from the params, falling back on a block
+/// repeated from `min_repeats` to `max_repeats` times in decimal. A `repeats`
+/// param asks for exactly that many repeats. Part one falls back on exactly
+/// two repeats, and part two on two or more.
+pub fn pattern(params: &Params, min_repeats: u32, max_repeats: Option<u32>) -> Result<Pattern> {
+    let (min_repeats, max_repeats) = match params.get("repeats")? {
+        Some(repeats) => (repeats, Some(repeats)),
+        None => (
+            params.get_or("min_repeats", min_repeats)?,
+            params.get("max_repeats")?.or(max_repeats),
+        ),
+    };
+    Pattern::new(min_repeats, max_repeats, params.get_or("radix", 10)?)
+}
+
+/// Adds up the invalid IDs, failing rather than overflowing.
+fn sum(ids: impl IntoIterator<Item = u128>) -> Result<u128> {
+    ids.into_iter()
+        .try_fold(0u128, |total, id| total.checked_add(id))
+        .ok_or_else(|| Error::overflow("the sum of the invalid IDs"))
+}
+
+/// Adds up the IDs which match `pattern`, working through each length of ID
+/// in each range without looking at the IDs themselves.
+pub fn sum_invalid(ranges: &[Range], pattern: &Pattern) -> Result<u128> {
+    Ok(pattern.scan(ranges)?.sum)
+}
+
+/// Writes out the digits of each ID and compares them with each of their
+/// prefixes repeated.
+pub fn reference_scan(ranges: &[Range], pattern: &Pattern) -> Result<u128> {
+    let radix = u128::from(pattern.radix());
+    let mut total = 0;
+    for range in ranges {
+        for id in range.start()..=range.end() {
+            let mut digits = Vec::new();
+            let mut rest = id;
+            loop {
+                digits.push(rest % radix);
+                rest /= radix;
+                if rest == 0 {
+                    break;
+                }
+            }
+            let len = digits.len();
+            let invalid = (1..=len / 2).any(|block| {
+                let repeats = len / block;
+                len.is_multiple_of(block)
+                    && u32::try_from(repeats).is_ok_and(|repeats| pattern.allows(repeats))
+                    && digits[..block].repeat(repeats) == digits
+            });
+            if invalid {
+                total = sum([total, id])?;
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+/// `size` disjoint ID ranges, on one line in no particular order. The ranges
+/// spread out over more digits as they go, so that there are IDs of every
+/// length.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut ranges = Vec::with_capacity(size);
+    let mut start = rng.between(1, 100);
+    for _ in 0..size {
+        let end = start + rng.between(0, 5000);
+        ranges.push(format!("{start}-{end}"));
+        let gap = if end < 1_000_000_000_000 {
+            end / 4
+        } else {
+            1000
+        };
+        start = end + rng.between(1, gap + 10);
+    }
+    rng.shuffle(&mut ranges);
+    ranges.join(",") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_references() {
+        let twice = Pattern::exactly(2).unwrap();
+        let any = Pattern::at_least(2).unwrap();
+        for first in (0..1200).step_by(7) {
+            for width in [0, 1, 10, 95, 1000] {
+                let ranges = [Range::new(first, first + width)];
+                assert_eq!(
+                    sum_invalid(&ranges, &twice),
+                    reference_scan(&ranges, &twice),
+                    "{ranges:?}"
+                );
+                assert_eq!(
+                    sum_invalid(&ranges, &any),
+                    reference_scan(&ranges, &any),
+                    "{ranges:?}"
+                );
+            }
+        }
+        let ranges = [
+            Range::new(99_990, 1_001_010),
+            Range::new(111_111_000, 111_112_000),
+        ];
+        assert_eq!(sum_invalid(&ranges, &any), reference_scan(&ranges, &any));
+    }
+
+    #[test]
+    fn test_wide_ranges() {
+        let twice = Pattern::exactly(2).unwrap();
+        let any = Pattern::at_least(2).unwrap();
+        // Every two digit ID of the form `aa` and every four digit `abab`.
+        let ranges = [Range::new(1, 9999)];
+        let pairs: u128 = (1..=9).map(|a| a * 11).sum();
+        let quads: u128 = (10..=99).map(|ab| ab * 101).sum();
+        assert_eq!(sum_invalid(&ranges, &twice), Ok(pairs + quads));
+
+        let ranges = [Range::new(1, 10u128.pow(18))];
+        assert!(sum_invalid(&ranges, &any).unwrap() > sum_invalid(&ranges, &twice).unwrap());
+        assert_eq!(
+            sum_invalid(&[Range::new(0, u128::MAX)], &twice),
+            Err(Error::overflow("the sum of the invalid IDs"))
+        );
+        assert_eq!(
+            sum_invalid(&[Range::new(u128::MAX - 5, u128::MAX)], &twice),
+            Ok(0)
+        );
+    }
+
+    #[test]
+    fn test_pattern_params() {
+        let params = Params::new();
+        assert_eq!(pattern(&params, 2, None), Pattern::at_least(2));
+        let params = Params::new().with("repeats", 3);
+        assert_eq!(pattern(&params, 2, None), Pattern::exactly(3));
+        let params = Params::new()
+            .with("min_repeats", 3)
+            .with("max_repeats", 5)
+            .with("radix", 16);
+        assert_eq!(pattern(&params, 2, None), Pattern::new(3, Some(5), 16));
+        let params = Params::new().with("radix", 1);
+        assert!(pattern(&params, 2, None).is_err());
+    }
+}
@@ -1,55 +1,7 @@
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let sample = include_str!("data-sample.txt").trim();
-    let actual = include_str!("data-actual.txt").trim();
+use std::process::ExitCode;
 
-    assert_eq!(part_one(sample)?, 1_227_775_554);
-    println!("Part One: {}", part_one(actual)?);
+use aoc_2025_02::Day02;
 
-    assert_eq!(part_two(sample)?, 4_174_379_265);
-    println!("Part Two: {}", part_two(actual)?);
-
-    Ok(())
-}
-
-fn part_one(file: &str) -> Result<u64, Box<dyn std::error::Error>> {
-    let ids = file.split(',');
-    let mut total = 0;
-    for id in ids {
-        let (first_id, last_id) = id.split_once('-').unwrap();
-        for i in first_id.parse::<u64>()?..=last_id.parse::<u64>()? {
-            let s = i.to_string();
-            let len = s.len();
-            if len % 2 != 0 {
-                continue;
-            }
-            let (first_half, second_half) = s.split_at(len / 2);
-            if first_half == second_half {
-                total += i;
-            }
-        }
-    }
-
-    Ok(total)
-}
-
-fn part_two(file: &str) -> Result<u64, Box<dyn std::error::Error>> {
-    let ids = file.split(',');
-    let mut total = 0;
-    for id in ids {
-        let (first_id, last_id) = id.split_once('-').unwrap();
-        for i in first_id.parse::<u64>()?..=last_id.parse::<u64>()? {
-            let s = i.to_string();
-            let len = s.len();
-            for n in 1..=(len / 2) {
-                let current_str = &s[0..n];
-                let repeated = current_str.repeat(len / n);
-                if repeated == s {
-                    total += i;
-                    break;
-                }
-            }
-        }
-    }
-
-    Ok(total)
+fn main() -> ExitCode {
+    aoc_common::standalone::run(&Day02, include_str!("../../answers.toml"))
 }
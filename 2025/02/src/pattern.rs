@@ -0,0 +1,375 @@
+//! Which IDs are invalid: those made of a block of digits repeated some
+//! number of times, such as `123123`, in any base.
+//!
+//! An ID of `len` digits repeated `repeats` times is its block times a
+//! multiplier, e.g. `123123` is `123` times `1001`, so the IDs of each length
+//! and repeat count within a range are found from the range's ends without
+//! looking at any IDs in between.
+
+use aoc_common::error::{Error, Result};
+use aoc_common::range::Range;
+
+/// The repeat counts which make an ID invalid, and the base its digits are
+/// written in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pattern {
+    min_repeats: u32,
+    /// `None` for any number of repeats.
+    max_repeats: Option<u32>,
+    radix: u32,
+}
+
+/// How many IDs matched a [`Pattern`], and what they add up to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Tally {
+    pub count: u128,
+    pub sum: u128,
+}
+
+impl Tally {
+    fn checked_add(self, other: Tally) -> Result<Tally> {
+        Ok(Tally {
+            count: self
+                .count
+                .checked_add(other.count)
+                .ok_or_else(|| Error::overflow("the number of invalid IDs"))?,
+            sum: self
+                .sum
+                .checked_add(other.sum)
+                .ok_or_else(|| Error::overflow("the sum of the invalid IDs"))?,
+        })
+    }
+}
+
+/// The IDs of one length which are some block repeated a given number of
+/// times: every block from `first_block` to `last_block` times `multiplier`.
+#[derive(Clone, Debug, PartialEq)]
+struct Repeated {
+    multiplier: u128,
+    first_block: u128,
+    last_block: u128,
+}
+
+impl Repeated {
+    /// Sums the blocks as an arithmetic series, halving whichever of the
+    /// count and the sum of the ends is even before multiplying.
+    fn tally(&self) -> Result<Tally> {
+        if self.first_block > self.last_block {
+            return Ok(Tally::default());
+        }
+        let overflow = || Error::overflow("the sum of the invalid IDs");
+        let count = self.last_block - self.first_block + 1;
+        let ends = self
+            .first_block
+            .checked_add(self.last_block)
+            .ok_or_else(overflow)?;
+        let blocks = if count.is_multiple_of(2) {
+            (count / 2).checked_mul(ends)
+        } else {
+            count.checked_mul(ends / 2)
+        };
+        let sum = blocks
+            .and_then(|blocks| blocks.checked_mul(self.multiplier))
+            .ok_or_else(overflow)?;
+        Ok(Tally { count, sum })
+    }
+}
+
+impl Pattern {
+    /// IDs whose block is repeated from `min_repeats` to `max_repeats` times
+    /// in base `radix`. Each argument has a param of the same name, which an
+    /// error points at.
+    pub fn new(min_repeats: u32, max_repeats: Option<u32>, radix: u32) -> Result<Self> {
+        if min_repeats < 2 {
+            return Err(Error::param(
+                "min_repeats",
+                min_repeats,
+                "a block must be repeated at least twice",
+            ));
+        }
+        if let Some(max) = max_repeats
+            && max < min_repeats
+        {
+            return Err(Error::param(
+                "max_repeats",
+                max,
+                format!("expected at least the minimum of {min_repeats}"),
+            ));
+        }
+        if !(2..=36).contains(&radix) {
+            return Err(Error::param("radix", radix, "expected a base from 2 to 36"));
+        }
+        Ok(Pattern {
+            min_repeats,
+            max_repeats,
+            radix,
+        })
+    }
+
+    /// Decimal IDs whose block is repeated exactly `repeats` times.
+    pub fn exactly(repeats: u32) -> Result<Self> {
+        Self::new(repeats, Some(repeats), 10)
+    }
+
+    /// Decimal IDs whose block is repeated `repeats` or more times.
+    pub fn at_least(repeats: u32) -> Result<Self> {
+        Self::new(repeats, None, 10)
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Whether a block repeated `repeats` times makes an ID invalid.
+    pub fn allows(&self, repeats: u32) -> bool {
+        repeats >= self.min_repeats && self.max_repeats.is_none_or(|max| repeats <= max)
+    }
+
+    /// The number of digits in `id`.
+    fn len(&self, id: u128) -> u32 {
+        id.checked_ilog(u128::from(self.radix)).unwrap_or(0) + 1
+    }
+
+    /// The repeat counts, allowed or not, that an ID of `len` digits can
+    /// have.
+    fn repeat_counts(len: u32) -> impl DoubleEndedIterator<Item = u32> {
+        (2..=len).filter(move |repeats| len.is_multiple_of(*repeats))
+    }
+
+    /// The IDs in `low..=high`, which all have `len` digits, that are a block
+    /// repeated `repeats` times. `None` if there can't be any, because such
+    /// IDs are too big for a `u128`.
+    fn repeated(&self, len: u32, repeats: u32, low: u128, high: u128) -> Option<Repeated> {
+        let radix = u128::from(self.radix);
+        let block_limit = radix.checked_pow(len / repeats)?;
+        let multiplier = (1..repeats).try_fold(1u128, |multiplier, _| {
+            multiplier.checked_mul(block_limit)?.checked_add(1)
+        })?;
+        Some(Repeated {
+            multiplier,
+            first_block: low.div_ceil(multiplier).max(block_limit / radix),
+            last_block: (high / multiplier).min(block_limit - 1),
+        })
+    }
+
+    /// The lengths of the IDs in `first..=last`, each with the part of the
+    /// range which has that many digits.
+    fn by_length(&self, first: u128, last: u128) -> impl Iterator<Item = (u32, u128, u128)> {
+        let radix = u128::from(self.radix);
+        (self.len(first)..=self.len(last)).filter_map(move |len| {
+            let low = if len == 1 {
+                first
+            } else {
+                first.max(radix.pow(len - 1))
+            };
+            let high = radix
+                .checked_pow(len)
+                .map_or(last, |limit| last.min(limit - 1));
+            (low <= high).then_some((len, low, high))
+        })
+    }
+
+    /// Whether `id` is a block of digits repeated an allowed number of
+    /// times.
+    pub fn matches(&self, id: u128) -> bool {
+        let len = self.len(id);
+        Self::repeat_counts(len)
+            .filter(|&repeats| self.allows(repeats))
+            .filter_map(|repeats| self.repeated(len, repeats, id, id))
+            .any(|repeated| repeated.first_block <= repeated.last_block)
+    }
+
+    /// Counts and adds up the matching IDs in `first..=last`.
+    ///
+    /// Every ID of `len` digits which is a repeated block at all is some
+    /// shortest block repeated `m` times, and so is also a block repeated
+    /// `k` times for exactly the divisors `k` of `m`. Working down from the
+    /// most repeats, the IDs repeated `m` times less those already found for
+    /// multiples of `m` leaves those whose shortest block is repeated `m`
+    /// times, and they match when an allowed count divides `m`.
+    pub fn tally(&self, first: u128, last: u128) -> Result<Tally> {
+        let mut tally = Tally::default();
+        for (len, low, high) in self.by_length(first, last) {
+            let mut shortest: Vec<(u32, Tally)> = Vec::new();
+            for repeats in Self::repeat_counts(len).rev() {
+                if !Self::repeat_counts(repeats).any(|k| self.allows(k)) {
+                    continue;
+                }
+                let Some(repeated) = self.repeated(len, repeats, low, high) else {
+                    continue;
+                };
+                let mut ids = repeated.tally()?;
+                for (more, found) in &shortest {
+                    if more.is_multiple_of(repeats) {
+                        ids.count -= found.count;
+                        ids.sum -= found.sum;
+                    }
+                }
+                shortest.push((repeats, ids));
+                tally = tally.checked_add(ids)?;
+            }
+        }
+        Ok(tally)
+    }
+
+    /// [`Pattern::tally`] over every range.
+    pub fn scan(&self, ranges: &[Range]) -> Result<Tally> {
+        ranges.iter().try_fold(Tally::default(), |tally, range| {
+            tally.checked_add(self.tally(range.start(), range.end())?)
+        })
+    }
+
+    /// The matching IDs in `first..=last`, in order.
+    pub fn ids(&self, first: u128, last: u128) -> Ids<'_> {
+        Ids {
+            pattern: self,
+            lengths: self.by_length(first, last).collect::<Vec<_>>().into_iter(),
+            repeated: Vec::new(),
+        }
+    }
+}
+
+/// The IDs matching a [`Pattern`] in a range, in order, from
+/// [`Pattern::ids`]. An ID repeated more than one allowed number of times
+/// comes up once.
+pub struct Ids<'a> {
+    pattern: &'a Pattern,
+    lengths: std::vec::IntoIter<(u32, u128, u128)>,
+    /// The IDs left for each allowed repeat count of the current length.
+    repeated: Vec<Repeated>,
+}
+
+impl Iterator for Ids<'_> {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        loop {
+            self.repeated.retain(|r| r.first_block <= r.last_block);
+            let next = self
+                .repeated
+                .iter()
+                .map(|r| r.first_block * r.multiplier)
+                .min();
+            if let Some(id) = next {
+                for r in &mut self.repeated {
+                    if r.first_block * r.multiplier == id {
+                        r.first_block += 1;
+                    }
+                }
+                return Some(id);
+            }
+
+            let (len, low, high) = self.lengths.next()?;
+            self.repeated = Pattern::repeat_counts(len)
+                .filter(|&repeats| self.pattern.allows(repeats))
+                .filter_map(|repeats| self.pattern.repeated(len, repeats, low, high))
+                .collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether the digits of `id` are a block repeated an allowed number of
+    /// times, by writing them out.
+    fn matches_slowly(pattern: &Pattern, mut id: u128) -> bool {
+        let radix = u128::from(pattern.radix);
+        let mut digits = vec![id % radix];
+        while id >= radix {
+            id /= radix;
+            digits.push(id % radix);
+        }
+        let len = digits.len();
+        (2..=len).any(|repeats| {
+            len.is_multiple_of(repeats)
+                && pattern.allows(u32::try_from(repeats).unwrap())
+                && digits[..len / repeats].repeat(repeats) == digits
+        })
+    }
+
+    fn patterns() -> Vec<Pattern> {
+        let mut patterns = Vec::new();
+        for radix in [2, 3, 10] {
+            for (min, max) in [
+                (2, Some(2)),
+                (2, None),
+                (3, Some(3)),
+                (2, Some(3)),
+                (4, None),
+            ] {
+                patterns.push(Pattern::new(min, max, radix).unwrap());
+            }
+        }
+        patterns
+    }
+
+    #[test]
+    fn test_matches_writing_out_digits() {
+        for pattern in patterns() {
+            for id in 0..5000 {
+                assert_eq!(
+                    pattern.matches(id),
+                    matches_slowly(&pattern, id),
+                    "{id} with {pattern:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_tally_and_ids_match_scanning() {
+        for pattern in patterns() {
+            for first in (0..3000).step_by(53) {
+                for width in [0, 5, 100, 1000] {
+                    let last = first + width;
+                    let expected: Vec<u128> = (first..=last)
+                        .filter(|&id| matches_slowly(&pattern, id))
+                        .collect();
+                    let ids: Vec<u128> = pattern.ids(first, last).collect();
+                    assert_eq!(ids, expected, "{first}-{last} with {pattern:?}");
+                    let tally = Tally {
+                        count: expected.len() as u128,
+                        sum: expected.iter().sum(),
+                    };
+                    assert_eq!(pattern.tally(first, last), Ok(tally));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let twice = Pattern::exactly(2).unwrap();
+        let ids: Vec<u128> = twice.ids(95, 1200).collect();
+        assert_eq!(ids, [99, 1010, 1111]);
+        assert_eq!(
+            twice.scan(&[Range::new(11, 22), Range::new(95, 115)]),
+            Ok(Tally { count: 3, sum: 132 })
+        );
+
+        let binary = Pattern::new(2, None, 2).unwrap();
+        // 0b11, 0b111, 0b1010, 0b1111.
+        let ids: Vec<u128> = binary.ids(0, 15).collect();
+        assert_eq!(ids, [3, 7, 10, 15]);
+
+        assert_eq!(Pattern::exactly(2).unwrap().ids(30, 10).count(), 0);
+        assert_eq!(
+            Pattern::at_least(2)
+                .unwrap()
+                .tally(u128::MAX - 1000, u128::MAX),
+            Ok(Tally::default())
+        );
+    }
+
+    #[test]
+    fn test_new_errors() {
+        assert!(Pattern::exactly(1).is_err());
+        assert!(Pattern::new(3, Some(2), 10).is_err());
+        assert_eq!(
+            Pattern::new(2, None, 37).unwrap_err().to_string(),
+            "invalid value `37` for parameter `radix`: expected a base from 2 to 36"
+        );
+    }
+}
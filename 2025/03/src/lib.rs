@@ -0,0 +1,148 @@
+use aoc_common::error::{Error, Line, Result, lines};
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<Bank>;
+    type PartOne = u32;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Bank>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Vec<Bank>, _params: &Params) -> Result<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<Bank>, _params: &Params) -> Result<u64> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// A bank of batteries and the line it was read from.
+pub struct Bank {
+    line: usize,
+    digits: Vec<u32>,
+}
+
+impl Bank {
+    /// Reads a bank of batteries, one joltage digit per battery.
+    fn parse(line: Line) -> Result<Bank> {
+        let digits = line
+            .text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(10).ok_or_else(|| {
+                    line.error_at_column(i, format!("expected a digit, found `{c}`"))
+                })
+            })
+            .collect::<Result<Vec<u32>>>()?;
+        Ok(Bank {
+            line: line.number,
+            digits,
+        })
+    }
+
+    /// The joltage digits, as long as there are at least `min_len` of them.
+    fn digits(&self, min_len: usize) -> Result<&[u32]> {
+        if self.digits.len() < min_len {
+            return Err(Error::parse(
+                self.line,
+                self.digits.len() + 1,
+                format!(
+                    "expected at least {min_len} batteries, found {}",
+                    self.digits.len()
+                ),
+            ));
+        }
+        Ok(&self.digits)
+    }
+}
+
+pub fn parse(file: &str) -> Result<Vec<Bank>> {
+    lines(file).map(Bank::parse).collect()
+}
+
+pub fn part_one(banks: &[Bank]) -> Result<u32> {
+    let mut total: u32 = 0;
+    for bank in banks {
+        let digits = bank.digits(2)?;
+        let mut highest_start = 0;
+        let mut highest_end = 0;
+        for window in digits.windows(2) {
+            let current = window[0];
+            let next = window[1];
+            if current > highest_start {
+                highest_end = next;
+                highest_start = current;
+            } else {
+                if current > highest_end {
+                    highest_end = current;
+                }
+                if next > highest_end {
+                    highest_end = next;
+                }
+            }
+        }
+
+        let line_total = (highest_start * 10) + highest_end;
+        total += line_total;
+    }
+
+    Ok(total)
+}
+
+pub fn part_two(banks: &[Bank]) -> Result<u64> {
+    let mut total: u64 = 0;
+    for bank in banks {
+        let digits = bank.digits(12)?;
+        let digit_len = digits.len();
+        let mut start_index = 0;
+        let mut line_total: u64 = 0;
+        for i in 0..12 {
+            let mut highest_in_range = 0;
+            let digit_range = start_index..(digit_len - (11 - i));
+            for j in digit_range {
+                let current = digits[j];
+                if current > highest_in_range {
+                    highest_in_range = current;
+                    start_index = j + 1;
+                }
+            }
+            line_total += u64::from(highest_in_range) * 10u64.pow(11 - u32::try_from(i).unwrap());
+        }
+
+        total += line_total;
+    }
+
+    Ok(total)
+}
+
+/// `size` banks of 100 batteries each, with joltages from 1 to 9.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut bank: String = (0..100)
+                .map(|_| char::from(b'0' + u8::try_from(rng.between(1, 9)).unwrap()))
+                .collect();
+            bank.push('\n');
+            bank
+        })
+        .collect()
+}
@@ -0,0 +1,158 @@
+use aoc_common::error::Result;
+use aoc_common::grid::Grid;
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Grid;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Grid> {
+        Grid::from_lines(&mut input.lines())
+    }
+
+    fn part_one(input: &Grid, _params: &Params) -> Result<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Grid, _params: &Params) -> Result<u32> {
+        part_two(input)
+    }
+
+    fn reference_part_two(input: &Grid, _params: &Params) -> Option<Result<u32>> {
+        Some(reference_part_two(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_one(grid: &Grid) -> Result<u32> {
+    let mut total: u32 = 0;
+
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            let cell = grid.get_cell(x, y);
+            if !matches!(cell, Some('@')) {
+                continue;
+            }
+            let neighbours = grid.get_neighbours(x, y);
+            let paper_count = neighbours.into_iter().filter(|c| c == &'@').count();
+            if paper_count < 4 {
+                total += 1;
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+/// The positions around `(x, y)` which are inside the grid.
+fn neighbours(grid: &Grid, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+    let (width, height) = (grid.width(), grid.height());
+    (y.saturating_sub(1)..=usize::min(y + 1, height - 1))
+        .flat_map(move |ny| {
+            (x.saturating_sub(1)..=usize::min(x + 1, width - 1)).map(move |nx| (nx, ny))
+        })
+        .filter(move |&position| position != (x, y))
+}
+
+/// Removes rolls as soon as they become accessible, only looking again at
+/// the neighbours of each removed roll. Removing a roll never makes another
+/// inaccessible, so this removes the same rolls as rescanning the whole grid.
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_two(grid: &Grid) -> Result<u32> {
+    let width = grid.width();
+    let is_roll = |x, y| matches!(grid.get_cell(x, y), Some('@'));
+    // The number of neighbouring rolls of every roll still in the grid.
+    let mut counts: Vec<Option<usize>> = vec![None; width * grid.height()];
+    let mut accessible = Vec::new();
+    for y in 0..grid.height() {
+        for x in 0..width {
+            if is_roll(x, y) {
+                let count = neighbours(grid, x, y)
+                    .filter(|&(nx, ny)| is_roll(nx, ny))
+                    .count();
+                counts[y * width + x] = Some(count);
+                if count < 4 {
+                    accessible.push((x, y));
+                }
+            }
+        }
+    }
+
+    let mut total: u32 = 0;
+    while let Some((x, y)) = accessible.pop() {
+        if counts[y * width + x].take().is_none() {
+            continue;
+        }
+        total += 1;
+        for (nx, ny) in neighbours(grid, x, y) {
+            if let Some(count) = &mut counts[ny * width + nx] {
+                *count -= 1;
+                if *count == 3 {
+                    accessible.push((nx, ny));
+                }
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+/// Rescans the whole grid after every round of removals.
+#[allow(clippy::unnecessary_wraps)]
+pub fn reference_part_two(grid: &Grid) -> Result<u32> {
+    let mut grid = grid.clone();
+    let mut total: u32 = 0;
+
+    let mut did_remove = true;
+    while did_remove {
+        did_remove = false;
+        let mut next_grid = grid.clone();
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                let cell = grid.get_cell(x, y);
+                if !matches!(cell, Some('@')) {
+                    continue;
+                }
+                let neighbours = grid.get_neighbours(x, y);
+                let paper_count = neighbours.into_iter().filter(|c| c == &'@').count();
+                if paper_count < 4 {
+                    total += 1;
+                    did_remove = true;
+                    next_grid.set_cell(x, y, '.');
+                }
+            }
+        }
+        grid = next_grid;
+    }
+
+    Ok(total)
+}
+
+/// A `size` by `size` grid, with rolls of paper in about two thirds of it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..size)
+                .map(|_| if rng.chance(2, 3) { '@' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,134 @@
+use aoc_common::error::{Error, Result, lines};
+use aoc_common::random::Rng;
+use aoc_common::range::Range;
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Inventory;
+    type PartOne = u64;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Inventory> {
+        parse(input)
+    }
+
+    fn part_one(input: &Inventory, _params: &Params) -> Result<u64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Inventory, _params: &Params) -> Result<u128> {
+        part_two(input)
+    }
+
+    fn reference_part_one(input: &Inventory, _params: &Params) -> Option<Result<u64>> {
+        Some(reference_part_one(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// The fresh ingredient ID ranges followed, after a blank line, by the
+/// available ingredient IDs.
+pub struct Inventory {
+    ranges: Vec<Range>,
+    ids: Vec<u128>,
+}
+
+pub fn parse(file: &str) -> Result<Inventory> {
+    let mut ranges = Vec::new();
+    let mut ids = Vec::new();
+    let mut checking_ids = false;
+    for line in lines(file) {
+        if line.text.trim().is_empty() {
+            checking_ids = true;
+            continue;
+        }
+
+        if !checking_ids {
+            ranges.extend(Range::parse_line(line)?);
+        } else {
+            ids.push(line.parse(line.text)?);
+        }
+    }
+
+    Ok(Inventory { ranges, ids })
+}
+
+/// Merges the ranges, then looks each ID up with a binary search.
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_one(inventory: &Inventory) -> Result<u64> {
+    let merged = Range::merge(inventory.ranges.clone());
+    let fresh = inventory.ids.iter().filter(|&&id| {
+        let index = merged.partition_point(|r| r.end() < id);
+        merged.get(index).is_some_and(|r| r.contains(id))
+    });
+
+    Ok(fresh.count() as u64)
+}
+
+/// Checks every ID against every range.
+#[allow(clippy::unnecessary_wraps)]
+pub fn reference_part_one(inventory: &Inventory) -> Result<u64> {
+    let mut total: u64 = 0;
+    for &id in &inventory.ids {
+        if inventory.ranges.iter().any(|r| r.contains(id)) {
+            total += 1;
+        }
+    }
+
+    Ok(total)
+}
+
+pub fn part_two(inventory: &Inventory) -> Result<u128> {
+    let mut total: u128 = 0;
+
+    let merged = Range::merge(inventory.ranges.clone());
+
+    for r in merged {
+        total = r
+            .count()
+            .and_then(|count| total.checked_add(count))
+            .ok_or_else(|| Error::overflow("the number of fresh IDs"))?;
+    }
+
+    Ok(total)
+}
+
+/// `size` fresh ID ranges, some overlapping others, followed by five times
+/// as many IDs, about half of which are fresh.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut ranges: Vec<(u64, u64)> = Vec::with_capacity(size);
+    for _ in 0..size {
+        let start = match ranges.last() {
+            Some(&(start, end)) if rng.chance(1, 4) => rng.between(start, end),
+            _ => rng.between(1, 500_000_000_000_000),
+        };
+        ranges.push((start, start + rng.between(0, 10_000_000_000_000)));
+    }
+    rng.shuffle(&mut ranges);
+
+    let mut text: String = ranges.iter().map(|(s, e)| format!("{s}-{e}\n")).collect();
+    text.push('\n');
+    for _ in 0..size * 5 {
+        let id = if ranges.is_empty() || rng.chance(1, 2) {
+            rng.between(1, 510_000_000_000_000)
+        } else {
+            let &(start, end) = rng.pick(&ranges);
+            rng.between(start, end)
+        };
+        text.push_str(&format!("{id}\n"));
+    }
+    text
+}
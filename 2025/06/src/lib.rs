@@ -0,0 +1,294 @@
+use aoc_common::error::{Error, Result, lines};
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    /// The two parts read the worksheet so differently, by rows and by
+    /// columns, that each does its own parsing.
+    type Input<'a> = &'a str;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<&str> {
+        Ok(input)
+    }
+
+    fn part_one(input: &&str, _params: &Params) -> Result<u64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str, _params: &Params) -> Result<u64> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+struct Problem {
+    numbers: Vec<u64>,
+    solution: Option<u64>,
+    /// 1-based column the problem starts at, for reporting errors.
+    column: usize,
+}
+
+impl Problem {
+    pub fn new(column: usize) -> Self {
+        Self {
+            numbers: vec![],
+            solution: None,
+            column,
+        }
+    }
+
+    pub fn push(&mut self, n: u64) {
+        self.numbers.push(n);
+    }
+
+    pub fn add(&mut self) -> Result<()> {
+        self.solution = Some(sum(&self.numbers)?);
+        Ok(())
+    }
+
+    pub fn multiply(&mut self) -> Result<()> {
+        self.solution = Some(product(&self.numbers)?);
+        Ok(())
+    }
+}
+
+fn sum(numbers: &[u64]) -> Result<u64> {
+    numbers
+        .iter()
+        .try_fold(0u64, |total, &n| total.checked_add(n))
+        .ok_or_else(|| Error::overflow("a problem's answer"))
+}
+
+fn product(numbers: &[u64]) -> Result<u64> {
+    numbers
+        .iter()
+        .try_fold(1u64, |total, &n| total.checked_mul(n))
+        .ok_or_else(|| Error::overflow("a problem's answer"))
+}
+
+fn grand_total(total: u64, answer: u64) -> Result<u64> {
+    total
+        .checked_add(answer)
+        .ok_or_else(|| Error::overflow("the grand total"))
+}
+
+fn missing_operator(line: usize, column: usize) -> Error {
+    Error::parse(
+        line,
+        column,
+        "expected an operator (`+` or `*`) for this problem",
+    )
+}
+
+pub fn part_one(file: &str) -> Result<u64> {
+    let mut problems = Vec::<Problem>::new();
+    let mut line_count = 0;
+    for line in lines(file) {
+        line_count = line.number;
+        let mut problem_index = 0;
+        for section in line.text.split(' ') {
+            if section.is_empty() {
+                continue;
+            }
+            if problems.len() <= problem_index {
+                problems.push(Problem::new(line.column_of(section)));
+            }
+            let problem = &mut problems[problem_index];
+            match section {
+                "+" => problem.add()?,
+                "*" => problem.multiply()?,
+                n => problem.push(line.parse::<u64>(n)?),
+            }
+            problem_index += 1;
+        }
+    }
+
+    let mut total = 0;
+    for problem in problems {
+        let answer = problem
+            .solution
+            .ok_or_else(|| missing_operator(line_count, problem.column))?;
+        total = grand_total(total, answer)?;
+    }
+
+    Ok(total)
+}
+
+struct ColumnProblem {
+    numbers: Vec<u64>,
+    operator: Option<char>,
+    total: u64,
+}
+
+impl ColumnProblem {
+    pub fn new() -> Self {
+        Self {
+            numbers: vec![],
+            operator: None,
+            total: 0,
+        }
+    }
+
+    pub fn add_number(&mut self, n: u64) {
+        self.numbers.push(n);
+    }
+
+    /// Returns `false` if the problem already has an operator.
+    pub fn set_operator(&mut self, c: char) -> bool {
+        if self.operator.is_some() {
+            return false;
+        }
+        self.operator = Some(c);
+        true
+    }
+
+    /// Adds the current problem to the running total, or returns `None` if
+    /// the problem has no operator.
+    pub fn calculate(&mut self) -> Option<Result<u64>> {
+        let answer = match self.operator {
+            Some('+') => sum(&self.numbers),
+            Some('*') => product(&self.numbers),
+            _ => return None,
+        };
+        self.operator = None;
+        self.numbers.clear();
+        Some(answer.and_then(|answer| {
+            self.total = grand_total(self.total, answer)?;
+            Ok(self.total)
+        }))
+    }
+}
+
+/// Points at the first character in the 0-based `index`th column which can't
+/// be part of a number.
+fn invalid_column(file: &str, index: usize) -> Error {
+    for line in lines(file) {
+        if let Some(c) = line.text.chars().nth(index)
+            && !c.is_ascii_digit()
+            && c != ' '
+        {
+            return line.error_at_column(index, format!("expected a digit, found `{c}`"));
+        }
+    }
+    Error::parse(1, index + 1, "expected a number in this column")
+}
+
+pub fn part_two(file: &str) -> Result<u64> {
+    let lines = file.lines();
+    let line_count = lines.clone().count().max(1);
+    let columns: Vec<String> = lines.fold(Vec::new(), |mut acc, line| {
+        for (index, char) in line.chars().enumerate() {
+            match acc.get_mut(index) {
+                Some(s) => s.push(char),
+                _ => acc.push(char.to_string()),
+            }
+        }
+        acc
+    });
+
+    let mut problem = ColumnProblem::new();
+    for (index, column) in columns.iter().enumerate().rev() {
+        let mut column = column.trim();
+        if column.is_empty() {
+            problem
+                .calculate()
+                .ok_or_else(|| missing_operator(line_count, index + 2))??;
+            continue;
+        }
+
+        for operator in ['+', '*'] {
+            if let Some(c) = column.strip_suffix(operator) {
+                if !problem.set_operator(operator) {
+                    return Err(Error::parse(
+                        line_count,
+                        index + 1,
+                        "this problem already has an operator",
+                    ));
+                }
+                column = c.trim();
+            }
+        }
+        let num = column
+            .parse::<u64>()
+            .map_err(|_| invalid_column(file, index))?;
+        problem.add_number(num);
+    }
+
+    problem
+        .calculate()
+        .ok_or_else(|| missing_operator(line_count, 1))?
+}
+
+/// A worksheet of `size` problems, each with four numbers of up to three
+/// digits. The numbers of a problem are all aligned to its left or all to its
+/// right, which changes how they read in columns, and get longer or shorter
+/// going down so that no column has a gap between its digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut rows = vec![String::new(); 5];
+    for problem in 0..size {
+        let mut lengths: Vec<u32> = (0..4)
+            .map(|_| u32::try_from(rng.between(1, 3)).unwrap())
+            .collect();
+        lengths.sort_unstable();
+        if rng.chance(1, 2) {
+            lengths.reverse();
+        }
+        let width = lengths.iter().max().map_or(1, |&len| len as usize);
+        let left = rng.chance(1, 2);
+        for (row, digits) in rows.iter_mut().zip(lengths) {
+            let number = rng.between(10u64.pow(digits - 1), 10u64.pow(digits) - 1);
+            if problem > 0 {
+                row.push(' ');
+            }
+            if left {
+                row.push_str(&format!("{number:<width$}"));
+            } else {
+                row.push_str(&format!("{number:>width$}"));
+            }
+        }
+        if problem > 0 {
+            rows[4].push(' ');
+        }
+        rows[4].push_str(&format!("{:<width$}", rng.pick(&['+', '*'])));
+    }
+    rows.iter().map(|row| format!("{row}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_operator() {
+        let worksheet = "123 328\n 45 64\n*";
+        assert_eq!(part_one(worksheet), Err(missing_operator(3, 5)));
+        assert_eq!(part_two(worksheet), Err(missing_operator(3, 5)));
+    }
+
+    #[test]
+    fn test_invalid_numbers() {
+        assert_eq!(
+            part_one("123 3x8\n*   +").unwrap_err().to_string(),
+            "line 1, column 5: invalid number `3x8`: invalid digit found in string"
+        );
+        assert_eq!(
+            part_two("123 3x8\n*   +"),
+            Err(Error::parse(1, 6, "expected a digit, found `x`"))
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use aoc_common::error::{Error, Result, lines};
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<Vec<Cell>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Cell>>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Vec<Vec<Cell>>, _params: &Params) -> Result<u64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<Vec<Cell>>, _params: &Params) -> Result<u64> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell {
+    Empty,
+    Start,
+    Splitter,
+}
+
+/// Reads the manifold row by row. A splitter at either end of a row would
+/// send a beam out of the manifold, so isn't allowed.
+pub fn parse(file: &str) -> Result<Vec<Vec<Cell>>> {
+    lines(file)
+        .map(|line| {
+            let last = line.text.chars().count().saturating_sub(1);
+            line.text
+                .chars()
+                .enumerate()
+                .map(|(col, c)| match c {
+                    'S' => Ok(Cell::Start),
+                    '^' if col == 0 || col == last => Err(line
+                        .error_at_column(col, "a splitter can't be at the edge of the manifold")),
+                    '^' => Ok(Cell::Splitter),
+                    '.' => Ok(Cell::Empty),
+                    c => {
+                        Err(line.error_at_column(col, format!("unexpected `{c}` in the manifold")))
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+struct Beam {
+    columns: HashSet<usize>,
+    next_columns: HashSet<usize>,
+    splits: u64,
+}
+
+impl Beam {
+    pub fn new() -> Self {
+        Self {
+            columns: HashSet::new(),
+            next_columns: HashSet::new(),
+            splits: 0,
+        }
+    }
+
+    pub fn start(&mut self, col: usize) {
+        self.next_columns.insert(col);
+    }
+
+    pub fn travel(&mut self) {
+        self.columns = self.next_columns.clone();
+    }
+
+    pub fn split(&mut self, col: usize) {
+        if self.columns.contains(&col) {
+            self.next_columns.remove(&col);
+            self.next_columns.insert(col - 1);
+            self.next_columns.insert(col + 1);
+            self.splits += 1;
+        }
+    }
+
+    pub fn get_splits(&self) -> u64 {
+        self.splits
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_one(manifold: &[Vec<Cell>]) -> Result<u64> {
+    let mut beam = Beam::new();
+    for row in manifold {
+        for (col, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Start => beam.start(col),
+                Cell::Splitter => beam.split(col),
+                Cell::Empty => {}
+            }
+        }
+        beam.travel();
+    }
+
+    Ok(beam.get_splits())
+}
+
+struct TimelineBeam {
+    timelines: HashMap<usize, u64>,
+    next_timelines: HashMap<usize, u64>,
+}
+
+impl TimelineBeam {
+    pub fn new() -> Self {
+        Self {
+            timelines: HashMap::new(),
+            next_timelines: HashMap::new(),
+        }
+    }
+
+    pub fn start(&mut self, col: usize) {
+        self.next_timelines.insert(col, 1);
+    }
+
+    pub fn split(&mut self, col: usize) -> Result<()> {
+        if let Some(&count) = self.timelines.get(&col)
+            && count > 0
+        {
+            for next in [col - 1, col + 1] {
+                let timelines = self.next_timelines.entry(next).or_insert(0);
+                *timelines = add_timelines(*timelines, count)?;
+            }
+            self.next_timelines.insert(col, 0);
+        }
+        Ok(())
+    }
+
+    pub fn travel(&mut self) {
+        self.timelines = self.next_timelines.clone();
+    }
+
+    pub fn count_timelines(&self) -> Result<u64> {
+        self.timelines
+            .values()
+            .try_fold(0, |total, &count| add_timelines(total, count))
+    }
+}
+
+fn add_timelines(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b)
+        .ok_or_else(|| Error::overflow("the number of timelines"))
+}
+
+pub fn part_two(manifold: &[Vec<Cell>]) -> Result<u64> {
+    let mut beam = TimelineBeam::new();
+    for row in manifold {
+        for (col, cell) in row.iter().enumerate() {
+            match cell {
+                Cell::Start => beam.start(col),
+                Cell::Splitter => beam.split(col)?,
+                Cell::Empty => {}
+            }
+        }
+        beam.travel();
+    }
+
+    beam.count_timelines()
+}
+
+/// A manifold with `size` rows of splitters, wide enough that no beam can
+/// leave it. Splitters which would take the number of timelines past 2^60
+/// are left out, so that part two can't overflow.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const LIMIT: u128 = 1 << 60;
+    let width = 2 * size + 1;
+    let centre = size;
+    let mut rows = Vec::with_capacity(2 * size + 2);
+    let mut start = vec!['.'; width];
+    start[centre] = 'S';
+    rows.push(start);
+
+    let mut timelines = vec![0u128; width];
+    timelines[centre] = 1;
+    let mut total = 1;
+    for splitter_row in 0..size {
+        rows.push(vec!['.'; width]);
+        let mut row = vec!['.'; width];
+        let mut next = timelines.clone();
+        // Beams are at most `splitter_row` columns from the centre, an even
+        // or odd number of columns away as the rows go.
+        for col in (centre - splitter_row..=centre + splitter_row).step_by(2) {
+            let count = timelines[col];
+            if rng.chance(2, 3) && total + count <= LIMIT {
+                row[col] = '^';
+                next[col] -= count;
+                next[col - 1] += count;
+                next[col + 1] += count;
+                total += count;
+            }
+        }
+        timelines = next;
+        rows.push(row);
+    }
+    rows.push(vec!['.'; width]);
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,250 @@
+use aoc_common::error::{Error, Result, lines};
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<JunctionBox<'a>>;
+    type PartOne = u64;
+    type PartTwo = f64;
+
+    fn parse(input: &str) -> Result<Vec<JunctionBox<'_>>> {
+        parse_boxes(input)
+    }
+
+    fn part_one(input: &Vec<JunctionBox<'_>>, params: &Params) -> Result<u64> {
+        part_one(input, params.get_or("iterations", 1000)?)
+    }
+
+    fn part_two(input: &Vec<JunctionBox<'_>>, _params: &Params) -> Result<f64> {
+        part_two(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+fn calculate_distance(b1: &JunctionBox, b2: &JunctionBox) -> f64 {
+    let JunctionBox {
+        x: x1,
+        y: y1,
+        z: z1,
+        ..
+    } = b1;
+    let JunctionBox {
+        x: x2,
+        y: y2,
+        z: z2,
+        ..
+    } = b2;
+
+    ((x2 - x1).powi(2) + (y2 - y1).powi(2) + (z2 - z1).powi(2)).sqrt()
+}
+
+#[derive(Clone, Debug)]
+pub struct JunctionBox<'a> {
+    id: &'a str,
+    x: f64,
+    y: f64,
+    z: f64,
+    circuit: Circuit,
+}
+
+impl PartialEq for JunctionBox<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for JunctionBox<'_> {}
+
+#[derive(Debug)]
+struct Connection {
+    /// Index in the array for item a
+    a: usize,
+    /// Index in the array for item b
+    b: usize,
+    distance: f64,
+}
+
+impl PartialEq for Connection {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+}
+
+impl Eq for Connection {}
+
+impl PartialOrd for Connection {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Connection {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.distance.total_cmp(&other.distance)
+    }
+}
+
+type Circuit = u32;
+
+/// Reads one junction box per line, as `x,y,z`, each in its own circuit.
+pub fn parse_boxes(file: &str) -> Result<Vec<JunctionBox<'_>>> {
+    let mut boxes = Vec::new();
+    for (index, line) in lines(file).enumerate() {
+        let mut parts = line.text.split(',');
+        let mut coordinate = || {
+            let part = parts
+                .next()
+                .ok_or_else(|| line.error_at_end("expected three coordinates, `x,y,z`"))?;
+            line.parse::<f64>(part)
+        };
+        let (x, y, z) = (coordinate()?, coordinate()?, coordinate()?);
+        if let Some(extra) = parts.next() {
+            return Err(line.error_at(extra, "expected only three coordinates"));
+        }
+        boxes.push(JunctionBox {
+            id: line.text,
+            x,
+            y,
+            z,
+            circuit: u32::try_from(index)
+                .map_err(|_| line.error_at(line.text, "too many junction boxes"))?,
+        });
+    }
+    Ok(boxes)
+}
+
+pub fn part_one(boxes: &[JunctionBox], iterations: usize) -> Result<u64> {
+    let mut boxes = boxes.to_vec();
+
+    // build a list of all the connections
+    let mut connections: Vec<Connection> = Vec::new();
+    let len = boxes.len();
+    for i in 0..len {
+        for j in (i + 1)..len {
+            let distance = calculate_distance(boxes.get(i).unwrap(), boxes.get(j).unwrap());
+            connections.push(Connection {
+                a: i,
+                b: j,
+                distance,
+            });
+        }
+    }
+    connections.sort();
+
+    for counter in 0..iterations {
+        let shortest = connections.get(counter).ok_or_else(|| {
+            Error::no_solution(format!(
+                "only {} connections can be made, not {iterations}",
+                connections.len()
+            ))
+        })?;
+
+        let mut replacement_circuit = None;
+        {
+            let a = boxes.get(shortest.a).unwrap();
+            let b = boxes.get(shortest.b).unwrap();
+
+            if a.circuit != b.circuit {
+                replacement_circuit = Some((a.circuit, b.circuit));
+            }
+        }
+
+        if let Some((from, to)) = replacement_circuit {
+            for other in &mut boxes {
+                if other.circuit == from {
+                    other.circuit = to;
+                }
+            }
+        }
+    }
+
+    let mut circuit_sizes: HashMap<u32, u64> = HashMap::new();
+    for b in boxes {
+        circuit_sizes
+            .entry(b.circuit)
+            .and_modify(|count| *count += 1)
+            .or_insert(1);
+    }
+
+    let mut all_sizes: Vec<u64> = circuit_sizes.into_values().collect();
+    all_sizes.sort_unstable();
+    let highest_three = all_sizes.iter().rev().take(3).product();
+
+    Ok(highest_three)
+}
+
+pub fn part_two(boxes: &[JunctionBox]) -> Result<f64> {
+    let mut boxes = boxes.to_vec();
+
+    // build a list of all the connections
+    let mut connections: Vec<Connection> = Vec::new();
+    let len = boxes.len();
+    for i in 0..len {
+        for j in (i + 1)..len {
+            let distance = calculate_distance(boxes.get(i).unwrap(), boxes.get(j).unwrap());
+            connections.push(Connection {
+                a: i,
+                b: j,
+                distance,
+            });
+        }
+    }
+    connections.sort();
+
+    for connection in connections {
+        let mut replacement_circuit = None;
+        {
+            let a = boxes.get(connection.a).unwrap();
+            let b = boxes.get(connection.b).unwrap();
+
+            if a.circuit != b.circuit {
+                replacement_circuit = Some((a.circuit, b.circuit));
+            }
+        }
+
+        if let Some((from, to)) = replacement_circuit {
+            for other in &mut boxes {
+                if other.circuit == from {
+                    other.circuit = to;
+                }
+            }
+        }
+
+        let first_circuit = boxes.first().unwrap().circuit;
+        if boxes.iter().all(|b| b.circuit == first_circuit) {
+            let a = boxes.get(connection.a).unwrap();
+            let b = boxes.get(connection.b).unwrap();
+            return Ok(a.x * b.x);
+        }
+    }
+
+    Err(Error::no_solution("ran out of connections"))
+}
+
+/// `size` junction boxes at distinct random positions in a 100000 unit cube.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut seen = HashSet::new();
+    let mut text = String::new();
+    while seen.len() < size {
+        let position = [(); 3].map(|()| rng.below(100_000));
+        if seen.insert(position) {
+            let [x, y, z] = position;
+            text.push_str(&format!("{x},{y},{z}\n"));
+        }
+    }
+    text
+}
@@ -0,0 +1,263 @@
+use aoc_common::error::{Error, Result, lines};
+use aoc_common::geometry::{Rect, Tile};
+use aoc_common::random::Rng;
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<Tile>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Tile>> {
+        parse_tiles(input)
+    }
+
+    fn part_one(input: &Vec<Tile>, _params: &Params) -> Result<u64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<Tile>, _params: &Params) -> Result<u64> {
+        part_two(input)
+    }
+
+    fn reference_part_two(input: &Vec<Tile>, _params: &Params) -> Option<Result<u64>> {
+        Some(reference_part_two(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate(rng, size))
+    }
+}
+
+/// The largest coordinate allowed, either side of zero, which keeps the
+/// areas and cross products of tiles well within 64 bits.
+const MAX_COORDINATE: i64 = (1 << 30) - 1;
+
+/// Reads the red tiles, one `x,y` per line.
+pub fn parse_tiles(file: &str) -> Result<Vec<Tile>> {
+    lines(file)
+        .map(|line| {
+            let (x, y) = line.split_once(',')?;
+            let coordinate = |part| {
+                let value: i64 = line.parse(part)?;
+                if !(-MAX_COORDINATE..=MAX_COORDINATE).contains(&value) {
+                    return Err(line.error_at(
+                        part,
+                        format!(
+                            "expected a coordinate between -{MAX_COORDINATE} and {MAX_COORDINATE}"
+                        ),
+                    ));
+                }
+                Ok(value)
+            };
+            Ok(Tile {
+                x: coordinate(x)?,
+                y: coordinate(y)?,
+            })
+        })
+        .collect()
+}
+
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_one(tiles: &[Tile]) -> Result<u64> {
+    let mut largest = 0;
+    let len = tiles.len();
+    for i in 0..len {
+        for j in (i + 1)..len {
+            let tile_a = tiles.get(i).unwrap();
+            let tile_b = tiles.get(j).unwrap();
+            let area = tile_a.area(tile_b);
+            if area > largest {
+                largest = area;
+            }
+        }
+    }
+
+    Ok(largest)
+}
+
+/// The distinct values of one coordinate, in a compressed form where the
+/// `i`th value is at index `2i + 1`, the values strictly between it and the
+/// next are at `2i + 2`, and index `0` is before them all.
+struct Axis(Vec<i64>);
+
+impl Axis {
+    fn new(values: impl Iterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.collect();
+        values.sort_unstable();
+        values.dedup();
+        Axis(values)
+    }
+
+    fn len(&self) -> usize {
+        2 * self.0.len() + 1
+    }
+
+    fn index(&self, value: i64) -> usize {
+        2 * self
+            .0
+            .binary_search(&value)
+            .expect("every corner is on the axis")
+            + 1
+    }
+
+    /// Whether the compressed `index` stands for any tiles at all, which a
+    /// gap between neighbouring values doesn't.
+    fn has_tiles(&self, index: usize) -> bool {
+        match index {
+            0 => false,
+            i if i % 2 == 1 => true,
+            i => i / 2 < self.0.len() && self.0[i / 2] - self.0[i / 2 - 1] > 1,
+        }
+    }
+}
+
+/// Compresses the polygon onto a grid with a cell for every corner's row and
+/// column and one for each gap between them, finds the cells outside it with
+/// a flood fill, then checks each rectangle by counting the outside tiles it
+/// covers with a summed-area table.
+pub fn part_two(tiles: &[Tile]) -> Result<u64> {
+    let xs = Axis::new(tiles.iter().map(|t| t.x));
+    let ys = Axis::new(tiles.iter().map(|t| t.y));
+    let (width, height) = (xs.len(), ys.len());
+
+    let mut boundary = vec![false; width * height];
+    for (i, a) in tiles.iter().enumerate() {
+        let b = &tiles[(i + 1) % tiles.len()];
+        if a.x != b.x && a.y != b.y {
+            return Err(Error::no_solution(format!(
+                "red tiles {} and {} are not in the same row or column",
+                i + 1,
+                (i + 1) % tiles.len() + 1
+            )));
+        }
+        let (x1, x2) = (xs.index(a.x.min(b.x)), xs.index(a.x.max(b.x)));
+        let (y1, y2) = (ys.index(a.y.min(b.y)), ys.index(a.y.max(b.y)));
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                boundary[y * width + x] = true;
+            }
+        }
+    }
+
+    // The border of the compressed grid is always outside the polygon.
+    let mut outside = vec![false; width * height];
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    outside[0] = true;
+    while let Some((x, y)) = stack.pop() {
+        let next = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in next {
+            if nx < width && ny < height && !boundary[ny * width + nx] && !outside[ny * width + nx]
+            {
+                outside[ny * width + nx] = true;
+                stack.push((nx, ny));
+            }
+        }
+    }
+
+    // sums[y][x] counts the outside cells with tiles above and left of (x, y).
+    let mut sums = vec![0u32; (width + 1) * (height + 1)];
+    for y in 0..height {
+        for x in 0..width {
+            let cell = u32::from(outside[y * width + x] && xs.has_tiles(x) && ys.has_tiles(y));
+            sums[(y + 1) * (width + 1) + x + 1] =
+                cell + sums[y * (width + 1) + x + 1] + sums[(y + 1) * (width + 1) + x]
+                    - sums[y * (width + 1) + x];
+        }
+    }
+    let outside_in = |x1: usize, y1: usize, x2: usize, y2: usize| {
+        let at = |x: usize, y: usize| sums[y * (width + 1) + x];
+        at(x2 + 1, y2 + 1) + at(x1, y1) - at(x1, y2 + 1) - at(x2 + 1, y1)
+    };
+
+    let mut largest = 0;
+    for (i, a) in tiles.iter().enumerate() {
+        for b in &tiles[i + 1..] {
+            let area = a.area(b);
+            if area <= largest {
+                continue;
+            }
+            let (x1, x2) = (xs.index(a.x.min(b.x)), xs.index(a.x.max(b.x)));
+            let (y1, y2) = (ys.index(a.y.min(b.y)), ys.index(a.y.max(b.y)));
+            if outside_in(x1, y1, x2, y2) == 0 {
+                largest = area;
+            }
+        }
+    }
+
+    Ok(largest)
+}
+
+/// Checks every pair of corners with [`Rect::in_polygon`].
+#[allow(clippy::unnecessary_wraps)]
+pub fn reference_part_two(tiles: &[Tile]) -> Result<u64> {
+    let mut largest = 0;
+    let len = tiles.len();
+    for i in 0..len {
+        for j in (i + 1)..len {
+            let tile_a = tiles.get(i).unwrap();
+            let tile_b = tiles.get(j).unwrap();
+
+            let rect = Rect::from_points(tile_a, tile_b);
+            if rect.in_polygon(tiles) {
+                let area = tile_a.area(tile_b);
+                if area > largest {
+                    largest = area;
+                }
+            }
+        }
+    }
+
+    Ok(largest)
+}
+
+/// The red tiles of a polygon `size` columns wide, with a jagged top and
+/// bottom. Each column has its own top and bottom edge, with a step between
+/// neighbouring columns, and every top is above every nearby bottom so that
+/// the edges never cross.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut xs = vec![rng.between(1, 1000)];
+    for _ in 0..size {
+        let last = xs[xs.len() - 1];
+        xs.push(last + rng.between(2, 2000));
+    }
+    let mut heights = |low: u64, high: u64| {
+        let mut heights: Vec<u64> = Vec::with_capacity(size);
+        while heights.len() < size {
+            let height = rng.between(low, high);
+            if heights.last() != Some(&height) {
+                heights.push(height);
+            }
+        }
+        heights
+    };
+    let tops = heights(50_001, 99_999);
+    let bottoms = heights(1, 49_999);
+
+    let mut tiles = Vec::with_capacity(4 * size);
+    for (i, &top) in tops.iter().enumerate() {
+        tiles.push((xs[i], top));
+        tiles.push((xs[i + 1], top));
+    }
+    for (i, &bottom) in bottoms.iter().enumerate().rev() {
+        tiles.push((xs[i + 1], bottom));
+        tiles.push((xs[i], bottom));
+    }
+    tiles.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
@@ -0,0 +1,235 @@
+//! The per-year manifest of expected answers, `YEAR/answers.toml`.
+//!
+//! ```toml
+//! [08]
+//! sample = { part_one = 40, part_two = 25272, params = { iterations = 10 } }
+//! actual = { part_one = 330_786, part_two = 3_276_581_616 }
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use serde::Deserialize;
+
+use crate::input::InputKind;
+use crate::solution::{Params, Part};
+
+/// A TOML value written as a number or a string, kept as its display form so
+/// it can be compared against any answer type.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum Scalar {
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Scalar::Integer(n) => n.fmt(f),
+            Scalar::Float(n) => n.fmt(f),
+            Scalar::String(s) => s.fmt(f),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawExpected {
+    part_one: Option<Scalar>,
+    part_two: Option<Scalar>,
+    #[serde(default)]
+    params: BTreeMap<String, Scalar>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawDay {
+    sample: Option<RawExpected>,
+    actual: Option<RawExpected>,
+}
+
+/// The expected answers for one input, and the params to solve it with.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Expected {
+    pub params: Params,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl From<RawExpected> for Expected {
+    fn from(raw: RawExpected) -> Self {
+        Self {
+            params: raw
+                .params
+                .into_iter()
+                .fold(Params::new(), |params, (k, v)| params.with(&k, v)),
+            part_one: raw.part_one.map(|a| a.to_string()),
+            part_two: raw.part_two.map(|a| a.to_string()),
+        }
+    }
+}
+
+/// How a computed answer compares to the manifest.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Check {
+    Pass,
+    Mismatch {
+        expected: String,
+    },
+    /// The manifest has no answer recorded for this part.
+    Unknown,
+}
+
+impl Expected {
+    /// Parses a single table of answers and params, as used by the files in
+    /// a day's `samples` directory.
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(toml::from_str::<RawExpected>(text)?.into())
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: &str) -> Check {
+        match self.answer(part) {
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Mismatch {
+                expected: expected.to_string(),
+            },
+            None => Check::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DayAnswers {
+    pub sample: Option<Expected>,
+    pub actual: Option<Expected>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, kind: InputKind) -> Option<&Expected> {
+        match kind {
+            InputKind::Sample => self.sample.as_ref(),
+            InputKind::Actual => self.actual.as_ref(),
+        }
+    }
+}
+
+/// Every expected answer for a single year, keyed by day.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<u8, DayAnswers>);
+
+impl Answers {
+    pub fn parse(manifest: &str) -> Result<Self, Box<dyn Error>> {
+        let raw: BTreeMap<String, RawDay> = toml::from_str(manifest)?;
+        let mut days = BTreeMap::new();
+        for (key, day) in raw {
+            let number = key
+                .parse::<u8>()
+                .map_err(|_| format!("`{key}` is not a day number"))?;
+            days.insert(
+                number,
+                DayAnswers {
+                    sample: day.sample.map(Expected::from),
+                    actual: day.actual.map(Expected::from),
+                },
+            );
+        }
+        Ok(Self(days))
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayAnswers> {
+        self.0.get(&day)
+    }
+
+    /// The params to solve a day's input with, which are empty if the
+    /// manifest has none.
+    pub fn params(&self, day: u8, kind: InputKind) -> Params {
+        self.day(day)
+            .and_then(|a| a.get(kind))
+            .map(|e| e.params.clone())
+            .unwrap_or_default()
+    }
+
+    /// Every day with answers, in order.
+    pub fn days(&self) -> impl Iterator<Item = (u8, &DayAnswers)> {
+        self.0.iter().map(|(&day, answers)| (day, answers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[01]
+sample = { part_one = 3, part_two = 6 }
+actual = { part_one = 1076 }
+
+[08]
+sample = { part_one = 40, part_two = 25272, params = { iterations = 10 } }
+actual = { part_one = "330786", part_two = 3_276_581_616 }
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+
+        let day = answers.day(1).unwrap();
+        let sample = day.get(InputKind::Sample).unwrap();
+        assert_eq!(sample.answer(Part::One), Some("3"));
+        assert_eq!(sample.answer(Part::Two), Some("6"));
+        assert!(sample.params.is_empty());
+        assert_eq!(day.actual.as_ref().unwrap().answer(Part::Two), None);
+
+        let day = answers.day(8).unwrap();
+        let sample = day.get(InputKind::Sample).unwrap();
+        assert_eq!(sample.params, Params::new().with("iterations", 10));
+        let actual = day.get(InputKind::Actual).unwrap();
+        assert_eq!(actual.answer(Part::One), Some("330786"));
+        assert_eq!(actual.answer(Part::Two), Some("3276581616"));
+
+        assert!(answers.day(2).is_none());
+    }
+
+    #[test]
+    fn test_params() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        let iterations = Params::new().with("iterations", 10);
+        assert_eq!(answers.params(8, InputKind::Sample), iterations);
+        assert_eq!(answers.params(8, InputKind::Actual), Params::new());
+        assert_eq!(answers.params(2, InputKind::Actual), Params::new());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("[first]\nsample = { part_one = 1 }").is_err());
+        assert!(Answers::parse("[01]\nsample = { part_three = 1 }").is_err());
+        assert!(Answers::parse("[01]\nexample = { part_one = 1 }").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = Expected {
+            params: Params::new(),
+            part_one: Some("40".to_string()),
+            part_two: None,
+        };
+        assert_eq!(expected.check(Part::One, "40"), Check::Pass);
+        assert_eq!(
+            expected.check(Part::One, "41"),
+            Check::Mismatch {
+                expected: "40".to_string()
+            }
+        );
+        assert_eq!(expected.check(Part::Two, "1"), Check::Unknown);
+    }
+}
@@ -0,0 +1,186 @@
+//! Inputs committed in encrypted form, so that they can be versioned in a
+//! public repository without redistributing them.
+//!
+//! An encrypted input is a text file starting with [`HEADER`], followed by
+//! the hex encoded nonce and ciphertext. Anything without the header is used
+//! as it is, so plain and encrypted inputs can sit side by side.
+
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+pub const HEADER: &str = "aoc-encrypted-v1";
+
+/// Environment variable naming the key file, which is otherwise `aoc.key` at
+/// the root of the workspace.
+pub const KEY_ENV: &str = "AOC_KEY";
+
+const NONCE_LEN: usize = 24;
+const LINE_LEN: usize = 64;
+
+fn invalid(reason: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason.into())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, b| {
+        let _ = write!(hex, "{b:02x}");
+        hex
+    })
+}
+
+fn from_hex(hex: &str) -> io::Result<Vec<u8>> {
+    let digits: Vec<u8> = hex
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_digit(16).and_then(|d| u8::try_from(d).ok()))
+        .collect::<Option<_>>()
+        .ok_or_else(|| invalid("expected hex digits"))?;
+    if !digits.len().is_multiple_of(2) {
+        return Err(invalid("expected an even number of hex digits"));
+    }
+    Ok(digits
+        .chunks(2)
+        .map(|pair| pair[0] << 4 | pair[1])
+        .collect())
+}
+
+/// A secret key shared by everyone allowed to read the inputs.
+pub struct Key(chacha20poly1305::Key);
+
+impl Key {
+    pub fn generate() -> Self {
+        Self(XChaCha20Poly1305::generate_key(&mut OsRng))
+    }
+
+    pub fn parse(hex: &str) -> io::Result<Self> {
+        let bytes = from_hex(hex)?;
+        if bytes.len() != 32 {
+            return Err(invalid(format!(
+                "expected a 32 byte key, found {} bytes",
+                bytes.len()
+            )));
+        }
+        Ok(Self(*chacha20poly1305::Key::from_slice(&bytes)))
+    }
+
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.0)
+    }
+
+    /// `$AOC_KEY`, or `aoc.key` at the root of the workspace.
+    pub fn default_path() -> PathBuf {
+        std::env::var_os(KEY_ENV).map_or_else(
+            || Path::new(env!("CARGO_MANIFEST_DIR")).join("../aoc.key"),
+            PathBuf::from,
+        )
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let hex = std::fs::read_to_string(path).map_err(|e| {
+            io::Error::new(
+                e.kind(),
+                format!("could not read the key {}: {e}", path.display()),
+            )
+        })?;
+        Self::parse(hex.trim()).map_err(|e| invalid(format!("{}: {e}", path.display())))
+    }
+}
+
+pub fn is_encrypted(text: &str) -> bool {
+    text.starts_with(HEADER)
+}
+
+pub fn encrypt(key: &Key, plaintext: &str) -> String {
+    let cipher = XChaCha20Poly1305::new(&key.0);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("encrypting into memory cannot fail");
+
+    let hex = to_hex(&nonce) + &to_hex(&ciphertext);
+    let mut text = format!("{HEADER}\n");
+    for line in hex.as_bytes().chunks(LINE_LEN) {
+        text.push_str(std::str::from_utf8(line).expect("hex is ascii"));
+        text.push('\n');
+    }
+    text
+}
+
+pub fn decrypt(key: &Key, text: &str) -> io::Result<String> {
+    let body = text
+        .strip_prefix(HEADER)
+        .ok_or_else(|| invalid("the input is not encrypted"))?;
+    let bytes = from_hex(body)?;
+    if bytes.len() < NONCE_LEN {
+        return Err(invalid("the encrypted input is truncated"));
+    }
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+    let plaintext = XChaCha20Poly1305::new(&key.0)
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| invalid("the input could not be decrypted, the key may be wrong"))?;
+    String::from_utf8(plaintext).map_err(|_| invalid("the decrypted input is not UTF-8"))
+}
+
+/// Returns `text` as it is, unless it's encrypted, in which case it is
+/// decrypted with the key from [`Key::default_path`].
+pub fn reveal(text: Cow<'static, str>) -> io::Result<Cow<'static, str>> {
+    reveal_with_key(text, &Key::default_path())
+}
+
+/// Like [`reveal`], but with the key at `key_path`.
+pub fn reveal_with_key(text: Cow<'static, str>, key_path: &Path) -> io::Result<Cow<'static, str>> {
+    if !is_encrypted(&text) {
+        return Ok(text);
+    }
+    let key = Key::load(key_path)
+        .map_err(|e| io::Error::new(e.kind(), format!("the input is encrypted, but {e}")))?;
+    decrypt(&key, &text).map(Cow::Owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, "L68\nL30\n");
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("L68"));
+        assert!(encrypted.lines().all(|line| line.len() <= LINE_LEN));
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), "L68\nL30\n");
+
+        let other = Key::generate();
+        assert!(decrypt(&other, &encrypted).is_err());
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+        let parsed = Key::parse(&key.to_hex()).unwrap();
+        assert_eq!(parsed.0, key.0);
+        assert!(Key::parse("abcd").is_err());
+        assert!(Key::parse(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_reveal_plain_text() {
+        assert_eq!(reveal(Cow::Borrowed("plain")).unwrap(), "plain");
+    }
+
+    #[test]
+    fn test_decrypt_errors() {
+        let key = Key::generate();
+        assert!(decrypt(&key, "plain").is_err());
+        assert!(decrypt(&key, &format!("{HEADER}\nabcd\n")).is_err());
+        let mut encrypted = encrypt(&key, "input");
+        encrypted.replace_range(HEADER.len() + 60..HEADER.len() + 61, "0");
+        encrypted.replace_range(HEADER.len() + 61..HEADER.len() + 62, "1");
+        assert!(decrypt(&key, &encrypted).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong while parsing or solving a puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The input is malformed. `line` and `column` are 1-based and `column`
+    /// counts characters, not bytes.
+    Parse {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    /// A puzzle parameter could not be used.
+    Param {
+        name: String,
+        value: String,
+        reason: String,
+    },
+    /// The input is well formed but doesn't have an answer.
+    NoSolution(String),
+    /// The answer, or a value on the way to it, is too big for its type.
+    Overflow(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, reason: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            reason: reason.into(),
+        }
+    }
+
+    /// An [`Error::Param`] for the parameter `name`, which was set to `value`.
+    pub fn param(name: impl Into<String>, value: impl ToString, reason: impl Into<String>) -> Self {
+        Error::Param {
+            name: name.into(),
+            value: value.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn no_solution(reason: impl Into<String>) -> Self {
+        Error::NoSolution(reason.into())
+    }
+
+    /// An [`Error::Overflow`] in computing `what`, e.g. `"the total"`.
+    pub fn overflow(what: impl Into<String>) -> Self {
+        Error::Overflow(what.into())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                reason,
+            } => write!(f, "line {line}, column {column}: {reason}"),
+            Error::Param {
+                name,
+                value,
+                reason,
+            } => write!(
+                f,
+                "invalid value `{value}` for parameter `{name}`: {reason}"
+            ),
+            Error::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            Error::Overflow(what) => write!(f, "{what} overflows"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A line of puzzle input which knows where it is, so that anything parsed
+/// from it can report a precise location.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Splits the input into numbered [`Line`]s.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    /// The 1-based column at which `part`, a slice of this line, starts.
+    /// Anything which isn't part of the line points just past its end.
+    pub fn column_of(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        let offset = if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            offset
+        } else {
+            self.text.len()
+        };
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// An error pointing at `part`, a slice of this line.
+    pub fn error_at(&self, part: &str, reason: impl Into<String>) -> Error {
+        Error::parse(self.number, self.column_of(part), reason)
+    }
+
+    /// An error pointing at the character in the 0-based `index`th column.
+    pub fn error_at_column(&self, index: usize, reason: impl Into<String>) -> Error {
+        Error::parse(self.number, index + 1, reason)
+    }
+
+    /// An error pointing just past the end of the line, for missing input.
+    pub fn error_at_end(&self, reason: impl Into<String>) -> Error {
+        Error::parse(self.number, self.text.chars().count() + 1, reason)
+    }
+
+    /// Parses `part`, a slice of this line, reporting failures at its column.
+    pub fn parse<T>(&self, part: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        part.trim().parse().map_err(|e| {
+            let reason = if part.trim().is_empty() {
+                "expected a number".to_string()
+            } else {
+                format!("invalid number `{}`: {e}", part.trim())
+            };
+            self.error_at(part, reason)
+        })
+    }
+
+    /// Splits the line around the first `separator`.
+    pub fn split_once(&self, separator: char) -> Result<(&'a str, &'a str)> {
+        self.text
+            .split_once(separator)
+            .ok_or_else(|| self.error_at_end(format!("expected `{separator}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_are_numbered_from_one() {
+        let numbered: Vec<_> = lines("a\nb").collect();
+        assert_eq!(numbered, vec![Line::new(1, "a"), Line::new(2, "b")]);
+    }
+
+    #[test]
+    fn test_column_of() {
+        let line = Line::new(1, "12-x4");
+        let (start, end) = line.split_once('-').unwrap();
+        assert_eq!(line.column_of(start), 1);
+        assert_eq!(line.column_of(end), 4);
+        assert_eq!(line.column_of("elsewhere"), 6);
+
+        let line = Line::new(1, "é,9");
+        assert_eq!(line.column_of(line.split_once(',').unwrap().1), 3);
+    }
+
+    #[test]
+    fn test_parse() {
+        let line = Line::new(3, "12-x4");
+        let (start, end) = line.split_once('-').unwrap();
+        assert_eq!(line.parse::<u32>(start), Ok(12));
+
+        let error = line.parse::<u32>(end).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: invalid number `x4`: invalid digit found in string"
+        );
+
+        let error = line.split_once(',').unwrap_err();
+        assert_eq!(error, Error::parse(3, 6, "expected `,`"));
+    }
+}
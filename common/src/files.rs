@@ -0,0 +1,43 @@
+//! Directories of input files kept alongside the code, such as a day's
+//! samples and its fuzz corpus.
+
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The files in `dir`, sorted by name, leaving out any subdirectories. A
+/// missing directory has no files.
+pub fn sorted_files(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("could not read {}: {e}", dir.display()).into()),
+    };
+    let mut paths = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|path| path.is_file());
+    paths.sort();
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::TempDir;
+
+    #[test]
+    fn test_sorted_files() {
+        let dir = TempDir::new("files");
+        assert!(sorted_files(&dir.join("missing")).unwrap().is_empty());
+
+        fs::write(dir.join("b"), "").unwrap();
+        fs::write(dir.join("a.txt"), "").unwrap();
+        fs::create_dir(dir.join("c")).unwrap();
+        assert_eq!(
+            sorted_files(&dir).unwrap(),
+            [dir.join("a.txt"), dir.join("b")]
+        );
+    }
+}
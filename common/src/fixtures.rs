@@ -0,0 +1,40 @@
+//! Tiny solutions for testing the code which runs, checks and fuzzes days,
+//! with the `test-util` feature.
+
+use crate::error::{Error, Result};
+use crate::solution::{Params, Solution};
+
+/// Reads one number per line. Part one counts them, and part two looks up
+/// the number at the index given by the first, which panics when that index
+/// is out of bounds.
+pub struct Indexes;
+
+impl Solution for Indexes {
+    const YEAR: u16 = 2000;
+    const DAY: u8 = 1;
+    const SAMPLE: &'static str = "1\n7";
+    const INPUT: &'static str = "1\n7";
+
+    type Input<'a> = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse()
+                    .map_err(|_| Error::parse(i + 1, 1, "expected a number"))
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Vec<usize>, _params: &Params) -> Result<usize> {
+        Ok(input.len())
+    }
+
+    fn part_two(input: &Vec<usize>, _params: &Params) -> Result<usize> {
+        Ok(input[input[0]])
+    }
+}
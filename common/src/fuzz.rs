@@ -0,0 +1,121 @@
+//! Fuzzing each day's parser and solution, so that any input gives an answer
+//! or an [`Error`](crate::error::Error) rather than a panic.
+//!
+//! The libFuzzer targets are in the `fuzz` crate at the root of the
+//! workspace, one per day, named like `y2025_d01`. Each keeps its corpus in
+//! `fuzz/corpus/<target>`, and the inputs committed there are solved again by
+//! `cargo test`, which doesn't need a nightly compiler.
+
+use std::error::Error;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::answers::Answers;
+use crate::files::sorted_files;
+use crate::input::InputKind;
+use crate::solution::{DynSolution, Params};
+
+/// One input from a fuzz target's corpus.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// The file name, which libFuzzer makes a hash of the contents.
+    pub name: String,
+    pub data: Vec<u8>,
+}
+
+/// The name of the fuzz target for a day, e.g. `y2025_d01`.
+pub fn target(year: u16, day: u8) -> String {
+    format!("y{year}_d{day:02}")
+}
+
+/// The corpus directory of a day's fuzz target, in this workspace.
+pub fn corpus_dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../fuzz/corpus")
+        .join(target(year, day))
+}
+
+/// Reads every file in `dir`, in order of name. A missing directory has an
+/// empty corpus.
+pub fn load(dir: &Path) -> Result<Vec<Entry>, Box<dyn Error>> {
+    sorted_files(dir)?
+        .iter()
+        .map(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let data = fs::read(path).map_err(|e| format!("could not read {name}: {e}"))?;
+            Ok(Entry {
+                name: name.into_owned(),
+                data,
+            })
+        })
+        .collect()
+}
+
+/// The params a day's fuzz target solves with, which are those of its sample
+/// in the year's `manifest`. Fuzzed inputs are about the size of a sample, so
+/// the params for the real input, like day 8's 1000 connections, would stop
+/// most of them short of an answer.
+pub fn params(manifest: &str, day: u8) -> Result<Params, Box<dyn Error>> {
+    Ok(Answers::parse(manifest)?.params(day, InputKind::Sample))
+}
+
+/// Parses `data` and solves both parts with `params`, which is all a fuzz
+/// target does. Input which isn't UTF-8 can't reach a solution, so is
+/// ignored.
+pub fn solve(solution: &dyn DynSolution, data: &[u8], params: &Params) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        solution.run(input, params);
+    }
+}
+
+/// Like [`solve`], but returns the message of any panic instead of unwinding.
+pub fn check(solution: &dyn DynSolution, data: &[u8], params: &Params) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| solve(solution, data, params))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::Indexes;
+    use crate::temp::TempDir;
+
+    #[test]
+    fn test_check() {
+        let params = Params::new();
+        assert_eq!(check(&Indexes, b"1\n7", &params), Ok(()));
+        assert_eq!(check(&Indexes, b"x", &params), Ok(()));
+        assert_eq!(check(&Indexes, &[0xff], &params), Ok(()));
+        let message = check(&Indexes, b"5", &params).unwrap_err();
+        assert!(message.contains("index out of bounds"), "{message}");
+    }
+
+    #[test]
+    fn test_params() {
+        let manifest = "[08]\nsample = { params = { iterations = 10 } }\nactual = {}";
+        let iterations = Params::new().with("iterations", 10);
+        assert_eq!(params(manifest, 8).unwrap(), iterations);
+        assert_eq!(params(manifest, 1).unwrap(), Params::new());
+        assert!(params("[first]", 1).is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = TempDir::new("fuzz");
+        fs::write(dir.join("b"), [0xff, 0]).unwrap();
+        fs::write(dir.join("a"), "L1").unwrap();
+        let corpus = load(&dir).unwrap();
+
+        let names: Vec<&str> = corpus.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["a", "b"]);
+        assert_eq!(corpus[0].data, b"L1");
+        assert_eq!(corpus[1].data, [0xff, 0]);
+        assert!(load(&dir.join("missing")).unwrap().is_empty());
+    }
+}
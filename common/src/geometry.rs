@@ -0,0 +1,272 @@
+/// A point on an integer grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tile {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Tile {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Area of the rectangle with this tile and `other` as opposite corners,
+    /// counting both corner tiles.
+    pub fn area(&self, other: &Tile) -> u64 {
+        let x = self.x.abs_diff(other.x) + 1;
+        let y = self.y.abs_diff(other.y) + 1;
+        x * y
+    }
+
+    /// Whether the tile lies inside, or on the boundary of, the polygon whose
+    /// vertices are `tiles` in order.
+    pub fn in_polygon(&self, tiles: &[Tile]) -> bool {
+        let mut inside = false;
+        let len = tiles.len();
+
+        for i in 0..len {
+            let a = tiles.get(i).unwrap();
+            let b = tiles.get((i + 1) % len).unwrap();
+
+            if tile_on_segment(self, a, b) {
+                return true;
+            }
+
+            let y1 = a.y;
+            let y2 = b.y;
+
+            if (y1 <= self.y && y2 > self.y) || (y2 <= self.y && y1 > self.y) {
+                let x_intersect = a.x + (self.y - y1) * (b.x - a.x) / (y2 - y1);
+
+                if x_intersect > self.x {
+                    inside = !inside;
+                }
+            }
+        }
+
+        inside
+    }
+}
+
+/// An axis-aligned rectangle described by its minimum and maximum corners.
+pub struct Rect {
+    pub min: Tile,
+    pub max: Tile,
+}
+
+impl Rect {
+    pub fn from_points(tile1: &Tile, tile2: &Tile) -> Self {
+        let min_x = tile1.x.min(tile2.x);
+        let min_y = tile1.y.min(tile2.y);
+        let max_x = tile1.x.max(tile2.x);
+        let max_y = tile1.y.max(tile2.y);
+
+        Rect {
+            min: Tile { x: min_x, y: min_y },
+            max: Tile { x: max_x, y: max_y },
+        }
+    }
+
+    /// Whether the whole rectangle fits inside the polygon whose vertices are
+    /// `tiles` in order.
+    pub fn in_polygon(&self, tiles: &[Tile]) -> bool {
+        let corners = [
+            &self.min,
+            &self.max,
+            &Tile {
+                x: self.min.x,
+                y: self.max.y,
+            },
+            &Tile {
+                x: self.max.x,
+                y: self.min.y,
+            },
+        ];
+
+        if !corners.iter().all(|tile| tile.in_polygon(tiles)) {
+            return false;
+        }
+
+        let rect_edges = [
+            (
+                &self.min,
+                &Tile {
+                    x: self.max.x,
+                    y: self.min.y,
+                },
+            ),
+            (
+                &Tile {
+                    x: self.max.x,
+                    y: self.min.y,
+                },
+                &self.max,
+            ),
+            (
+                &self.max,
+                &Tile {
+                    x: self.min.x,
+                    y: self.max.y,
+                },
+            ),
+            (
+                &Tile {
+                    x: self.min.x,
+                    y: self.max.y,
+                },
+                &self.min,
+            ),
+        ];
+
+        let len = tiles.len();
+        for i in 0..len {
+            let a = tiles.get(i).unwrap();
+            let b = tiles.get((i + 1) % len).unwrap();
+
+            if self.edge_through_interior(a, b) {
+                return false;
+            }
+            for (r1, r2) in rect_edges {
+                if segments_intersect(r1, r2, a, b) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Whether the horizontal or vertical segment from `a` to `b` passes
+    /// through the inside of the rectangle, not just along its edges. Such a
+    /// segment can enter through a corner without crossing any edge.
+    fn edge_through_interior(&self, a: &Tile, b: &Tile) -> bool {
+        let overlaps = |low: i64, high: i64, min: i64, max: i64| low.max(min) < high.min(max);
+        if a.x == b.x {
+            self.min.x < a.x
+                && a.x < self.max.x
+                && overlaps(a.y.min(b.y), a.y.max(b.y), self.min.y, self.max.y)
+        } else if a.y == b.y {
+            self.min.y < a.y
+                && a.y < self.max.y
+                && overlaps(a.x.min(b.x), a.x.max(b.x), self.min.x, self.max.x)
+        } else {
+            false
+        }
+    }
+}
+
+/// Whether `p` lies on the segment from `a` to `b`, including its end points.
+pub fn tile_on_segment(p: &Tile, a: &Tile, b: &Tile) -> bool {
+    let cross = (p.x - a.x) * (b.y - a.y) - (p.y - a.y) * (b.x - a.x);
+    if cross != 0 {
+        return false;
+    }
+
+    p.x >= a.x.min(b.x) && p.x <= a.x.max(b.x) && p.y >= a.y.min(b.y) && p.y <= a.y.max(b.y)
+}
+
+/// The sign of the cross product: positive when `a -> b -> c` turns
+/// counter-clockwise, negative when clockwise and zero when collinear.
+pub fn orient(a: &Tile, b: &Tile, c: &Tile) -> i64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Whether the segments `a1 -> a2` and `b1 -> b2` properly cross each other.
+/// Segments which only touch or overlap are not counted.
+pub fn segments_intersect(a1: &Tile, a2: &Tile, b1: &Tile, b2: &Tile) -> bool {
+    let o1 = orient(a1, a2, b1);
+    let o2 = orient(a1, a2, b2);
+    let o3 = orient(b1, b2, a1);
+    let o4 = orient(b1, b2, a2);
+
+    (o1 > 0 && o2 < 0 || o1 < 0 && o2 > 0) && (o3 > 0 && o4 < 0 || o3 < 0 && o4 > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_polygon() -> Vec<Tile> {
+        [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]
+        .into_iter()
+        .map(|(x, y)| Tile::new(x, y))
+        .collect()
+    }
+
+    #[test]
+    fn test_tile_area() {
+        assert_eq!(Tile::new(2, 5).area(&Tile::new(9, 7)), 24);
+        assert_eq!(Tile::new(9, 7).area(&Tile::new(2, 5)), 24);
+        assert_eq!(Tile::new(3, 3).area(&Tile::new(3, 3)), 1);
+    }
+
+    #[test]
+    fn test_tile_in_polygon() {
+        let polygon = sample_polygon();
+        assert!(Tile::new(8, 2).in_polygon(&polygon));
+        assert!(Tile::new(7, 1).in_polygon(&polygon));
+        assert!(Tile::new(2, 4).in_polygon(&polygon));
+        assert!(!Tile::new(3, 2).in_polygon(&polygon));
+        assert!(!Tile::new(12, 4).in_polygon(&polygon));
+        assert!(!Tile::new(5, 6).in_polygon(&polygon));
+    }
+
+    #[test]
+    fn test_rect_from_points() {
+        let rect = Rect::from_points(&Tile::new(9, 1), &Tile::new(2, 5));
+        assert_eq!(rect.min, Tile::new(2, 1));
+        assert_eq!(rect.max, Tile::new(9, 5));
+    }
+
+    #[test]
+    fn test_rect_in_polygon() {
+        let polygon = sample_polygon();
+        assert!(Rect::from_points(&Tile::new(9, 5), &Tile::new(2, 3)).in_polygon(&polygon));
+        assert!(Rect::from_points(&Tile::new(9, 7), &Tile::new(11, 1)).in_polygon(&polygon));
+        assert!(!Rect::from_points(&Tile::new(7, 1), &Tile::new(11, 7)).in_polygon(&polygon));
+        assert!(!Rect::from_points(&Tile::new(2, 5), &Tile::new(11, 1)).in_polygon(&polygon));
+
+        // Only touches the edge at x = 4 at a corner, but the notch above
+        // that edge is outside.
+        let polygon: Vec<Tile> = [(0, 9), (4, 9), (4, 5), (8, 5), (8, 0), (0, 0)]
+            .into_iter()
+            .map(|(x, y)| Tile::new(x, y))
+            .collect();
+        assert!(!Rect::from_points(&Tile::new(0, 9), &Tile::new(8, 5)).in_polygon(&polygon));
+        assert!(!Rect::from_points(&Tile::new(0, 9), &Tile::new(8, 0)).in_polygon(&polygon));
+        assert!(Rect::from_points(&Tile::new(0, 5), &Tile::new(8, 0)).in_polygon(&polygon));
+    }
+
+    #[test]
+    fn test_segments_intersect() {
+        let (a1, a2) = (Tile::new(0, 0), Tile::new(4, 4));
+        assert!(segments_intersect(
+            &a1,
+            &a2,
+            &Tile::new(0, 4),
+            &Tile::new(4, 0)
+        ));
+        assert!(!segments_intersect(
+            &a1,
+            &a2,
+            &Tile::new(5, 0),
+            &Tile::new(5, 4)
+        ));
+        // touching at an end point is not a crossing
+        assert!(!segments_intersect(
+            &a1,
+            &a2,
+            &Tile::new(4, 4),
+            &Tile::new(8, 0)
+        ));
+    }
+}
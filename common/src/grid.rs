@@ -0,0 +1,167 @@
+use std::str::Lines;
+
+use crate::error::{Error, Result};
+
+/// A rectangular grid of characters, stored row by row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    cells: Vec<char>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid {
+    /// Reads one row per line. Every row must have the same, non-zero, number
+    /// of cells.
+    pub fn from_lines(lines: &mut Lines) -> Result<Grid> {
+        let first_line = lines.next().unwrap_or_default();
+        let mut cells = first_line.chars().collect::<Vec<char>>();
+        let width = cells.len();
+        if width == 0 {
+            return Err(Error::parse(1, 1, "expected at least one cell"));
+        }
+
+        let mut height = 1;
+        for line in lines {
+            height += 1;
+            let row_start = cells.len();
+            cells.extend(line.chars());
+            let row_width = cells.len() - row_start;
+            if row_width != width {
+                return Err(Error::parse(
+                    height,
+                    row_width.min(width) + 1,
+                    format!("expected {width} cells in the row, found {row_width}"),
+                ));
+            }
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn set_cell(&mut self, x: usize, y: usize, cell: char) {
+        let index = y * self.width + x;
+        if let Some(c) = self.cells.get_mut(index) {
+            *c = cell;
+        }
+    }
+
+    pub fn get_cell(&self, x: usize, y: usize) -> Option<&char> {
+        let index = y * self.width + x;
+        self.cells.get(index)
+    }
+
+    /// Returns the (up to eight) cells surrounding `(x, y)`, row by row.
+    pub fn get_neighbours(&self, x: usize, y: usize) -> Vec<char> {
+        let mut neighbours = Vec::new();
+        for ny in y.saturating_sub(1)..=usize::min(y + 1, self.height - 1) {
+            for nx in x.saturating_sub(1)..=usize::min(x + 1, self.width - 1) {
+                if nx == x && ny == y {
+                    continue;
+                }
+                if let Some(&cell) = self.get_cell(nx, ny) {
+                    neighbours.push(cell);
+                }
+            }
+        }
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    #[test]
+    fn test_grid_from_lines() {
+        let grid = Grid::from_lines(&mut SAMPLE.lines()).unwrap();
+        assert_eq!(grid.width(), 10);
+        assert_eq!(grid.height(), 10);
+        assert_eq!(
+            grid.cells,
+            vec![
+                '.', '.', '@', '@', '.', '@', '@', '@', '@', '.', // Line 1
+                '@', '@', '@', '.', '@', '.', '@', '.', '@', '@', // Line 2
+                '@', '@', '@', '@', '@', '.', '@', '.', '@', '@', // Line 3
+                '@', '.', '@', '@', '@', '@', '.', '.', '@', '.', // Line 4
+                '@', '@', '.', '@', '@', '@', '@', '.', '@', '@', // Line 5
+                '.', '@', '@', '@', '@', '@', '@', '@', '.', '@', // Line 6
+                '.', '@', '.', '@', '.', '@', '.', '@', '@', '@', // Line 7
+                '@', '.', '@', '@', '@', '.', '@', '@', '@', '@', // Line 8
+                '.', '@', '@', '@', '@', '@', '@', '@', '@', '.', // Line 9
+                '@', '.', '@', '.', '@', '@', '@', '.', '@', '.', // Line 10
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grid_from_lines_errors() {
+        assert_eq!(
+            Grid::from_lines(&mut "".lines()),
+            Err(Error::parse(1, 1, "expected at least one cell"))
+        );
+        assert_eq!(
+            Grid::from_lines(&mut "..@\n.@\n@@.".lines()),
+            Err(Error::parse(2, 3, "expected 3 cells in the row, found 2"))
+        );
+        assert_eq!(
+            Grid::from_lines(&mut "..@\n.@@@".lines()),
+            Err(Error::parse(2, 4, "expected 3 cells in the row, found 4"))
+        );
+    }
+
+    #[test]
+    fn test_grid_get_set_cell() {
+        let mut grid = Grid::from_lines(&mut SAMPLE.lines()).unwrap();
+        assert_eq!(grid.get_cell(2, 0), Some(&'@'));
+        assert_eq!(grid.get_cell(0, 10), None);
+
+        grid.set_cell(2, 0, '.');
+        assert_eq!(grid.get_cell(2, 0), Some(&'.'));
+
+        // out of bounds writes are ignored
+        let before = grid.clone();
+        grid.set_cell(0, 10, '@');
+        assert_eq!(grid, before);
+    }
+
+    #[test]
+    fn test_grid_get_neighbours() {
+        let grid = Grid::from_lines(&mut SAMPLE.lines()).unwrap();
+        assert_eq!(grid.get_neighbours(0, 0), vec!['.', '@', '@']);
+        assert_eq!(
+            grid.get_neighbours(1, 1),
+            vec!['.', '.', '@', '@', '@', '@', '@', '@']
+        );
+        assert_eq!(
+            grid.get_neighbours(2, 5),
+            vec!['@', '.', '@', '@', '@', '@', '.', '@']
+        );
+        assert_eq!(grid.get_neighbours(9, 0), vec!['@', '@', '@']);
+        assert_eq!(grid.get_neighbours(0, 9), vec!['.', '@', '.']);
+        assert_eq!(grid.get_neighbours(9, 9), vec!['@', '.', '@']);
+    }
+}
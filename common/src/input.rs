@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::encryption::{Key, reveal_with_key};
+
+/// Environment variable naming the directory to look for puzzle inputs in.
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS";
+
+/// Which of a day's committed inputs to use.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Sample,
+    Actual,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Sample, InputKind::Actual];
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputKind::Sample => write!(f, "sample"),
+            InputKind::Actual => write!(f, "actual"),
+        }
+    }
+}
+
+/// Where puzzle inputs should be read from at runtime.
+///
+/// An explicit `file` wins over the inputs `dir`, which in turn wins over the
+/// input embedded in the solution.
+#[derive(Clone, Debug, Default)]
+pub struct Inputs {
+    /// A single input file to use, or `-` to read from stdin.
+    pub file: Option<PathBuf>,
+    /// A directory laid out as `<dir>/<year>/<day>.txt`, e.g. `2025/01.txt`.
+    pub dir: Option<PathBuf>,
+}
+
+/// Where a loaded input came from, for reporting.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Origin {
+    Embedded,
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Embedded => write!(f, "embedded input"),
+            Origin::Stdin => write!(f, "stdin"),
+            Origin::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub struct Input {
+    pub text: Cow<'static, str>,
+    pub origin: Origin,
+}
+
+impl Inputs {
+    /// Uses the inputs directory from `AOC_INPUTS`, if set.
+    pub fn from_env() -> Self {
+        Self {
+            file: None,
+            dir: std::env::var_os(INPUTS_DIR_ENV).map(PathBuf::from),
+        }
+    }
+
+    /// The configuration for a standalone day binary: the inputs directory
+    /// from `AOC_INPUTS`, and an input file (or `-` for stdin) given as the
+    /// first argument.
+    pub fn from_args() -> Self {
+        Self {
+            file: std::env::args_os().nth(1).map(PathBuf::from),
+            ..Self::from_env()
+        }
+    }
+
+    /// The path a day's input would have inside the inputs directory.
+    pub fn path_in_dir(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(year.to_string()).join(format!("{day:02}.txt"))
+    }
+
+    /// Loads the input for a day, falling back to `embedded` when neither an
+    /// explicit file nor a matching file in the inputs directory exists.
+    /// Encrypted inputs are decrypted, wherever they came from.
+    pub fn load(&self, year: u16, day: u8, embedded: &'static str) -> io::Result<Input> {
+        self.load_with_key(year, day, embedded, &Key::default_path())
+    }
+
+    /// Like [`Inputs::load`], but decrypts with the key at `key_path`.
+    pub fn load_with_key(
+        &self,
+        year: u16,
+        day: u8,
+        embedded: &'static str,
+        key_path: &Path,
+    ) -> io::Result<Input> {
+        let Input { text, origin } = self.load_raw(year, day, embedded)?;
+        Ok(Input {
+            text: reveal_with_key(text, key_path)?,
+            origin,
+        })
+    }
+
+    fn load_raw(&self, year: u16, day: u8, embedded: &'static str) -> io::Result<Input> {
+        if let Some(file) = &self.file {
+            if file.as_os_str() == "-" {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                return Ok(Input {
+                    text: Cow::Owned(text),
+                    origin: Origin::Stdin,
+                });
+            }
+            return read_file(file);
+        }
+
+        if let Some(dir) = &self.dir {
+            let path = Self::path_in_dir(dir, year, day);
+            if path.exists() {
+                return read_file(&path);
+            }
+        }
+
+        Ok(Input {
+            text: Cow::Borrowed(embedded),
+            origin: Origin::Embedded,
+        })
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<Input> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())))?;
+    Ok(Input {
+        text: Cow::Owned(text),
+        origin: Origin::File(path.to_path_buf()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::encrypt;
+    use crate::temp::TempDir;
+
+    fn temp_dir(name: &str) -> TempDir {
+        let dir = TempDir::new(&format!("input-{name}"));
+        std::fs::create_dir_all(dir.join("2025")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_embedded_fallback() {
+        let input = Inputs::default().load(2025, 1, "embedded").unwrap();
+        assert_eq!(input.text, "embedded");
+        assert_eq!(input.origin, Origin::Embedded);
+    }
+
+    #[test]
+    fn test_load_from_dir() {
+        let dir = temp_dir("dir");
+        std::fs::write(dir.join("2025/01.txt"), "from dir").unwrap();
+        let inputs = Inputs {
+            file: None,
+            dir: Some(dir.to_path_buf()),
+        };
+
+        let input = inputs.load(2025, 1, "embedded").unwrap();
+        assert_eq!(input.text, "from dir");
+        assert_eq!(input.origin, Origin::File(dir.join("2025/01.txt")));
+
+        // days without a file in the directory use the embedded input
+        let input = inputs.load(2025, 2, "embedded").unwrap();
+        assert_eq!(input.origin, Origin::Embedded);
+    }
+
+    #[test]
+    fn test_load_explicit_file() {
+        let dir = temp_dir("file");
+        let file = dir.join("mine.txt");
+        std::fs::write(&file, "explicit").unwrap();
+        std::fs::write(dir.join("2025/01.txt"), "from dir").unwrap();
+        let inputs = Inputs {
+            file: Some(file.clone()),
+            dir: Some(dir.to_path_buf()),
+        };
+
+        let input = inputs.load(2025, 1, "embedded").unwrap();
+        assert_eq!(input.text, "explicit");
+        assert_eq!(input.origin, Origin::File(file));
+
+        let missing = Inputs {
+            file: Some(dir.join("missing.txt")),
+            dir: None,
+        };
+        assert!(missing.load(2025, 1, "embedded").is_err());
+    }
+
+    #[test]
+    fn test_load_decrypts() {
+        let dir = temp_dir("encrypted");
+        let key = Key::generate();
+        std::fs::write(dir.join("aoc.key"), key.to_hex()).unwrap();
+        std::fs::write(dir.join("2025/01.txt"), encrypt(&key, "secret")).unwrap();
+        let inputs = Inputs {
+            file: None,
+            dir: Some(dir.to_path_buf()),
+        };
+
+        let key_path = dir.join("aoc.key");
+        let input = inputs
+            .load_with_key(2025, 1, "embedded", &key_path)
+            .unwrap();
+        assert_eq!(input.text, "secret");
+        assert_eq!(input.origin, Origin::File(dir.join("2025/01.txt")));
+
+        std::fs::write(dir.join("aoc.key"), Key::generate().to_hex()).unwrap();
+        assert!(
+            inputs
+                .load_with_key(2025, 1, "embedded", &key_path)
+                .is_err()
+        );
+        assert!(
+            inputs
+                .load_with_key(2025, 1, "embedded", &dir.join("missing.key"))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,20 @@
+//! Building blocks shared between the daily puzzle solutions.
+
+pub mod answers;
+pub mod encryption;
+pub mod error;
+pub mod files;
+#[cfg(any(test, feature = "test-util"))]
+pub mod fixtures;
+pub mod fuzz;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod property;
+pub mod random;
+pub mod range;
+pub mod samples;
+pub mod solution;
+pub mod standalone;
+pub mod temp;
+pub mod year;
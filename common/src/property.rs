@@ -0,0 +1,252 @@
+//! Checks solutions against their reference implementations on generated
+//! inputs, and shrinks any input they disagree on to something small enough
+//! to debug by hand.
+
+use std::fmt::{self, Display};
+
+use crate::error::Result;
+use crate::random::Rng;
+use crate::solution::{Answer, DynSolution, Params, Part};
+
+/// An input on which a part and its reference implementation disagree.
+#[derive(Debug)]
+pub struct Counterexample {
+    pub part: Part,
+    /// The seed and size the input was generated from, before shrinking.
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub answer: Result<Answer>,
+    pub reference: Answer,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answer = match &self.answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("an error ({e})"),
+        };
+        write!(
+            f,
+            "{} gave {answer} where the reference gave {} (seed {}, size {}), on:\n{}",
+            self.part, self.reference, self.seed, self.size, self.input
+        )
+    }
+}
+
+/// What a part and its reference made of one input.
+struct Outcome {
+    answer: Result<Answer>,
+    reference: Answer,
+}
+
+impl Outcome {
+    /// Solves `input` both ways. Inputs the reference can't solve aren't
+    /// valid, so have no outcome.
+    fn of(solution: &dyn DynSolution, part: Part, input: &str, params: &Params) -> Option<Self> {
+        let reference = solution.solve_reference(part, input, params)?.ok()?;
+        let answer = solution.solve(part, input, params);
+        Some(Outcome { answer, reference })
+    }
+
+    fn agrees(&self) -> bool {
+        self.answer.as_ref() == Ok(&self.reference)
+    }
+}
+
+/// The pieces an input can be shrunk by: its lines, or the comma separated
+/// items of an input which is a single line.
+fn units(input: &str) -> (Vec<&str>, &'static str) {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() == 1 && lines[0].contains(',') {
+        (lines[0].split(',').collect(), ",")
+    } else {
+        (lines, "\n")
+    }
+}
+
+fn join(units: &[&str], separator: &str) -> String {
+    units.join(separator) + "\n"
+}
+
+/// Finds a smaller input on which `part` still disagrees with its reference
+/// in the same way, first by generating smaller inputs from the same seed,
+/// then by removing ever smaller runs of lines from the input.
+fn shrink(
+    solution: &dyn DynSolution,
+    part: Part,
+    params: &Params,
+    seed: u64,
+    size: usize,
+    input: String,
+) -> String {
+    let still_fails = |candidate: &str, failed_with_answer: bool| {
+        Outcome::of(solution, part, candidate, params)
+            .is_some_and(|o| !o.agrees() && o.answer.is_ok() == failed_with_answer)
+    };
+    let failed_with_answer = solution.solve(part, &input, params).is_ok();
+
+    let mut input = (1..size)
+        .filter_map(|smaller| solution.generate(&mut Rng::new(seed), smaller))
+        .find(|candidate| still_fails(candidate, failed_with_answer))
+        .unwrap_or(input);
+
+    let mut chunk = units(&input).0.len() / 2;
+    while chunk > 0 {
+        let (pieces, separator) = units(&input);
+        let smaller = (0..pieces.len()).step_by(chunk).find_map(|start| {
+            let mut candidate = pieces.clone();
+            candidate.drain(start..(start + chunk).min(pieces.len()));
+            let candidate = join(&candidate, separator);
+            still_fails(&candidate, failed_with_answer).then_some(candidate)
+        });
+        match smaller {
+            Some(smaller) => {
+                input = smaller;
+                chunk = chunk.min(units(&input).0.len() / 2).max(1);
+            }
+            None => chunk /= 2,
+        }
+    }
+    input
+}
+
+/// Solves `cases` generated inputs, of sizes up to `max_size`, with every
+/// part that has a reference implementation and compares the answers.
+/// Returns how many comparisons were made, or the first counterexample,
+/// shrunk.
+pub fn check(
+    solution: &dyn DynSolution,
+    params: &Params,
+    cases: u64,
+    max_size: usize,
+) -> std::result::Result<usize, Box<Counterexample>> {
+    let mut compared = 0;
+    for seed in 0..cases {
+        let size = 1 + usize::try_from(seed).unwrap_or(0) % max_size.max(1);
+        let Some(input) = solution.generate(&mut Rng::new(seed), size) else {
+            return Ok(compared);
+        };
+        for part in Part::ALL {
+            let Some(outcome) = Outcome::of(solution, part, &input, params) else {
+                continue;
+            };
+            compared += 1;
+            if outcome.agrees() {
+                continue;
+            }
+
+            let input = shrink(solution, part, params, seed, size, input.clone());
+            let outcome = Outcome::of(solution, part, &input, params)
+                .expect("the shrunk input still disagrees");
+            return Err(Box::new(Counterexample {
+                part,
+                seed,
+                size,
+                input,
+                answer: outcome.answer,
+                reference: outcome.reference,
+            }));
+        }
+    }
+    Ok(compared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::solution::Solution;
+
+    /// Sums numbers, one per line, except that part one gets it wrong when
+    /// any number is over 90.
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        const SAMPLE: &'static str = "";
+        const INPUT: &'static str = "";
+
+        type Input<'a> = Vec<u64>;
+        type PartOne = u64;
+        type PartTwo = u64;
+
+        fn parse(input: &str) -> Result<Vec<u64>> {
+            input
+                .lines()
+                .map(|line| line.parse().map_err(|_| Error::parse(1, 1, "not a number")))
+                .collect()
+        }
+
+        fn part_one(input: &Vec<u64>, _params: &Params) -> Result<u64> {
+            Ok(input.iter().map(|&n| n.min(90)).sum())
+        }
+
+        fn part_two(input: &Vec<u64>, _params: &Params) -> Result<u64> {
+            Ok(input.iter().sum())
+        }
+
+        fn reference_part_one(input: &Vec<u64>, _params: &Params) -> Option<Result<u64>> {
+            Some(Ok(input.iter().sum()))
+        }
+
+        fn reference_part_two(input: &Vec<u64>, _params: &Params) -> Option<Result<u64>> {
+            Some(Ok(input.iter().sum()))
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+            Some((0..size).map(|_| format!("{}\n", rng.below(100))).collect())
+        }
+    }
+
+    #[test]
+    fn test_check_shrinks_counterexamples() {
+        let counterexample = check(&Sum, &Params::new(), 100, 30).unwrap_err();
+        assert_eq!(counterexample.part, Part::One);
+        let numbers: Vec<u64> = counterexample
+            .input
+            .lines()
+            .map(|l| l.parse().unwrap())
+            .collect();
+        assert_eq!(numbers.len(), 1, "{counterexample}");
+        assert!(numbers[0] > 90);
+    }
+
+    #[test]
+    fn test_check_counts_comparisons() {
+        struct Fixed;
+        impl Solution for Fixed {
+            const YEAR: u16 = 2000;
+            const DAY: u8 = 2;
+            const SAMPLE: &'static str = "";
+            const INPUT: &'static str = "";
+
+            type Input<'a> = ();
+            type PartOne = u8;
+            type PartTwo = u8;
+
+            fn parse(_input: &str) -> Result<()> {
+                Ok(())
+            }
+
+            fn part_one(_input: &(), _params: &Params) -> Result<u8> {
+                Ok(1)
+            }
+
+            fn part_two(_input: &(), _params: &Params) -> Result<u8> {
+                Ok(2)
+            }
+
+            fn reference_part_two(_input: &(), _params: &Params) -> Option<Result<u8>> {
+                Some(Ok(2))
+            }
+
+            fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+                Some(String::new())
+            }
+        }
+
+        assert_eq!(check(&Fixed, &Params::new(), 5, 3).unwrap(), 5);
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt::{self, Display};
+use std::num::IntErrorKind;
+
+use crate::error::{Error, Line, lines};
+
+/// An inclusive range of IDs, `start..=end`, which is never empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Range {
+    start: u128,
+    end: u128,
+}
+
+/// Why a list of ranges couldn't be parsed, and where.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseRangeError {
+    /// 1-based, like [`Error::Parse`].
+    pub line: usize,
+    pub column: usize,
+    pub kind: RangeErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RangeErrorKind {
+    /// Something other than `start-end`.
+    Malformed(String),
+    /// A bound which isn't a number.
+    InvalidNumber(String),
+    /// A bound too big for a `u128`.
+    Overflow(String),
+    /// A range which ends before it starts.
+    Reversed { start: u128, end: u128 },
+}
+
+impl Display for RangeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeErrorKind::Malformed(text) => {
+                write!(f, "expected a range such as `11-22`, found `{text}`")
+            }
+            RangeErrorKind::InvalidNumber(text) if text.is_empty() => {
+                write!(f, "expected a number")
+            }
+            RangeErrorKind::InvalidNumber(text) => write!(f, "invalid number `{text}`"),
+            RangeErrorKind::Overflow(text) => write!(f, "`{text}` is too big for an ID"),
+            RangeErrorKind::Reversed { start, end } => write!(
+                f,
+                "expected the range to end at or after its start, but {end} is before {start}"
+            ),
+        }
+    }
+}
+
+impl From<ParseRangeError> for Error {
+    fn from(error: ParseRangeError) -> Self {
+        Error::parse(error.line, error.column, error.kind.to_string())
+    }
+}
+
+impl Range {
+    /// # Panics
+    ///
+    /// If `end` is before `start`.
+    pub fn new(start: u128, end: u128) -> Self {
+        assert!(
+            start <= end,
+            "the range {start}-{end} ends before it starts"
+        );
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> u128 {
+        self.start
+    }
+
+    pub fn end(&self) -> u128 {
+        self.end
+    }
+
+    /// Parses a list of ranges such as `11-22,95-115`, separated by commas,
+    /// newlines or both. Whitespace around each range and its bounds is
+    /// ignored, as are empty entries, so trailing commas and blank lines are
+    /// fine.
+    pub fn parse_list(text: &str) -> Result<Vec<Range>, ParseRangeError> {
+        let mut ranges = Vec::new();
+        for line in lines(text) {
+            ranges.extend(Self::parse_line(line)?);
+        }
+        Ok(ranges)
+    }
+
+    /// Parses the comma separated ranges on one line of a list, for input
+    /// where the list is only part of the file.
+    pub fn parse_line(line: Line) -> Result<Vec<Range>, ParseRangeError> {
+        line.text
+            .split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(|entry| Self::parse_entry(line, entry))
+            .collect()
+    }
+
+    /// Parses `entry`, a slice of `line`.
+    fn parse_entry(line: Line, entry: &str) -> Result<Range, ParseRangeError> {
+        let error = |part: &str, kind| ParseRangeError {
+            line: line.number,
+            column: line.column_of(part),
+            kind,
+        };
+        let bound = |part: &str| {
+            let text = part.trim();
+            text.parse::<u128>().map_err(|e| {
+                let kind = if *e.kind() == IntErrorKind::PosOverflow {
+                    RangeErrorKind::Overflow(text.to_string())
+                } else {
+                    RangeErrorKind::InvalidNumber(text.to_string())
+                };
+                error(part.trim_start(), kind)
+            })
+        };
+
+        let entry = entry.trim();
+        let (first, last) = entry
+            .split_once('-')
+            .ok_or_else(|| error(entry, RangeErrorKind::Malformed(entry.to_string())))?;
+        let (start, end) = (bound(first)?, bound(last)?);
+        if end < start {
+            return Err(error(
+                last.trim_start(),
+                RangeErrorKind::Reversed { start, end },
+            ));
+        }
+        Ok(Range::new(start, end))
+    }
+
+    pub fn contains(&self, index: u128) -> bool {
+        self.start <= index && index <= self.end
+    }
+
+    /// The number of IDs in the range, or `None` for `0..=u128::MAX`, which
+    /// has one too many to count in a `u128`.
+    pub fn count(&self) -> Option<u128> {
+        (self.end - self.start).checked_add(1)
+    }
+
+    /// Sorts the ranges and combines any that overlap or touch, so that every
+    /// ID is covered by at most one of the returned ranges.
+    pub fn merge(mut ranges: Vec<Range>) -> Vec<Range> {
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range> = vec![];
+        for r in ranges {
+            if let Some(last) = merged.last_mut()
+                && r.start <= last.end.saturating_add(1)
+            {
+                last.end = last.end.max(r.end);
+            } else {
+                merged.push(r);
+            }
+        }
+
+        merged
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_contains() {
+        let range = Range::new(10, 14);
+        assert!(!range.contains(9));
+        assert!(range.contains(10));
+        assert!(range.contains(12));
+        assert!(range.contains(14));
+        assert!(!range.contains(15));
+    }
+
+    #[test]
+    fn test_range_count() {
+        assert_eq!(Range::new(3, 5).count(), Some(3));
+        assert_eq!(Range::new(7, 7).count(), Some(1));
+        assert_eq!(Range::new(1, u128::MAX).count(), Some(u128::MAX));
+        assert_eq!(Range::new(0, u128::MAX).count(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the range 5-3 ends before it starts")]
+    fn test_range_new_reversed() {
+        Range::new(5, 3);
+    }
+
+    #[test]
+    fn test_range_merge() {
+        let merged = Range::merge(vec![
+            Range::new(3, 5),
+            Range::new(10, 14),
+            Range::new(16, 20),
+            Range::new(12, 18),
+            Range::new(21, 22),
+            Range::new(30, 30),
+        ]);
+        assert_eq!(
+            merged,
+            vec![Range::new(3, 5), Range::new(10, 22), Range::new(30, 30)]
+        );
+
+        let merged = Range::merge(vec![
+            Range::new(5, u128::MAX),
+            Range::new(u128::MAX, u128::MAX),
+        ]);
+        assert_eq!(merged, vec![Range::new(5, u128::MAX)]);
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let expected = vec![Range::new(11, 22), Range::new(95, 115), Range::new(7, 7)];
+        assert_eq!(Range::parse_list("11-22,95-115,7-7"), Ok(expected.clone()));
+        assert_eq!(
+            Range::parse_list("11-22\n95-115\n7-7\n"),
+            Ok(expected.clone())
+        );
+        assert_eq!(
+            Range::parse_list(" 11 - 22 ,\n95-115,  7-7,\n\n"),
+            Ok(expected)
+        );
+        assert_eq!(Range::parse_list(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_list_errors() {
+        let error = |line, column, kind| Err(ParseRangeError { line, column, kind });
+        assert_eq!(
+            Range::parse_list("1-2,\n 5-3"),
+            error(2, 4, RangeErrorKind::Reversed { start: 5, end: 3 })
+        );
+        let huge = "1".repeat(40);
+        assert_eq!(
+            Range::parse_list(&format!("1-2, 3-{huge}")),
+            error(1, 8, RangeErrorKind::Overflow(huge))
+        );
+        assert_eq!(
+            Range::parse_list("1-2,  12"),
+            error(1, 7, RangeErrorKind::Malformed("12".to_string()))
+        );
+        assert_eq!(
+            Range::parse_list("x-2"),
+            error(1, 1, RangeErrorKind::InvalidNumber("x".to_string()))
+        );
+
+        let error = Error::from(Range::parse_list("1-").unwrap_err());
+        assert_eq!(error.to_string(), "line 1, column 3: expected a number");
+    }
+}
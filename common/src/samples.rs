@@ -0,0 +1,117 @@
+//! Extra examples for a day, kept as files in its `samples` directory
+//! alongside `data-sample.txt`.
+//!
+//! Each file starts with a TOML header, between `+++` lines, declaring the
+//! answers the example gives and any params to solve it with. Only the parts
+//! with an answer are checked, so an example which only applies to part two
+//! just leaves out `part_one`:
+//!
+//! ```text
+//! +++
+//! part_two = 10
+//! +++
+//! R1000
+//! ```
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::Expected;
+use crate::files::sorted_files;
+
+const DELIMITER: &str = "+++";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    /// The file name, e.g. `large-grid.txt`.
+    pub name: String,
+    pub expected: Expected,
+    pub text: String,
+}
+
+impl Sample {
+    pub fn parse(name: &str, text: &str) -> Result<Self, Box<dyn Error>> {
+        let header_error = || format!("{name}: expected a header between `{DELIMITER}` lines");
+        let rest = text
+            .strip_prefix(DELIMITER)
+            .and_then(|rest| rest.strip_prefix('\n').or(rest.strip_prefix("\r\n")))
+            .ok_or_else(header_error)?;
+        let end = rest
+            .lines()
+            .position(|line| line.trim_end() == DELIMITER)
+            .ok_or_else(header_error)?;
+        let header = rest.lines().take(end).collect::<Vec<_>>().join("\n");
+        let input = rest.lines().skip(end + 1).collect::<Vec<_>>().join("\n");
+
+        let expected = Expected::parse(&header).map_err(|e| format!("{name}: {e}"))?;
+        if expected.part_one.is_none() && expected.part_two.is_none() {
+            return Err(format!("{name}: expected an answer for at least one part").into());
+        }
+        Ok(Self {
+            name: name.to_string(),
+            expected,
+            text: input,
+        })
+    }
+}
+
+/// The samples directory of a day, in this workspace.
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("../{year}/{day:02}/samples"))
+}
+
+/// Reads every `.txt` file in `dir`, in order of name. A missing directory
+/// has no samples.
+pub fn load(dir: &Path) -> Result<Vec<Sample>, Box<dyn Error>> {
+    let mut paths = sorted_files(dir)?;
+    paths.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+
+    paths
+        .iter()
+        .map(|path| {
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            Sample::parse(&name, &text)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Params, Part};
+    use crate::temp::TempDir;
+
+    #[test]
+    fn test_parse() {
+        let text = "+++\npart_two = 10\nparams = { iterations = 10 }\n+++\nR1000\nL5\n";
+        let sample = Sample::parse("spin.txt", text).unwrap();
+        assert_eq!(sample.name, "spin.txt");
+        assert_eq!(sample.expected.answer(Part::One), None);
+        assert_eq!(sample.expected.answer(Part::Two), Some("10"));
+        assert_eq!(sample.expected.params, Params::new().with("iterations", 10));
+        assert_eq!(sample.text, "R1000\nL5");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Sample::parse("a.txt", "R1000\n").is_err());
+        assert!(Sample::parse("a.txt", "+++\npart_one = 1\nR1000\n").is_err());
+        assert!(Sample::parse("a.txt", "+++\n+++\nR1000\n").is_err());
+        assert!(Sample::parse("a.txt", "+++\npart_three = 1\n+++\nR1000\n").is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = TempDir::new("samples");
+        assert_eq!(load(&dir.join("missing")).unwrap(), Vec::new());
+
+        fs::write(dir.join("b.txt"), "+++\npart_one = 2\n+++\nb\n").unwrap();
+        fs::write(dir.join("a.txt"), "+++\npart_one = 1\n+++\na\n").unwrap();
+        fs::write(dir.join("notes.md"), "not a sample").unwrap();
+        let names: Vec<_> = load(&dir).unwrap().into_iter().map(|s| s.name).collect();
+        assert_eq!(names, ["a.txt", "b.txt"]);
+    }
+}
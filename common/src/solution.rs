@@ -0,0 +1,370 @@
+use std::any::type_name;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::input::InputKind;
+use crate::random::Rng;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// `1` or `2`, as the puzzle numbers its parts.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+/// Named values which tweak how a puzzle is solved without being part of the
+/// input, such as the number of connections to make in 2025 day 8 (`10` for
+/// the sample, `1000` for the real input).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: &str, value: impl ToString) -> Self {
+        self.0.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    /// Parses the parameter `name`, falling back to `default` when it is not
+    /// set.
+    pub fn get_or<T>(&self, name: &str, default: T) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Ok(self.get(name)?.unwrap_or(default))
+    }
+
+    /// Parses the parameter `name`, or returns `None` when it is not set.
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.0
+            .get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e: T::Err| Error::param(name, value, e.to_string()))
+            })
+            .transpose()
+    }
+}
+
+/// A solution to a single day's puzzle.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// The example input from the puzzle description.
+    const SAMPLE: &'static str;
+    /// The puzzle input committed alongside the solution.
+    const INPUT: &'static str;
+
+    /// The parsed input, shared by both parts.
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>>;
+    fn part_one(input: &Self::Input<'_>, params: &Params) -> Result<Self::PartOne>;
+    fn part_two(input: &Self::Input<'_>, params: &Params) -> Result<Self::PartTwo>;
+
+    /// A simple and obviously correct, if slow, version of part one, which
+    /// the property tests check `part_one` against. `None` if there isn't
+    /// one.
+    fn reference_part_one(
+        _input: &Self::Input<'_>,
+        _params: &Params,
+    ) -> Option<Result<Self::PartOne>> {
+        None
+    }
+
+    /// See [`Solution::reference_part_one`].
+    fn reference_part_two(
+        _input: &Self::Input<'_>,
+        _params: &Params,
+    ) -> Option<Result<Self::PartTwo>> {
+        None
+    }
+
+    /// A random but valid input, whose size grows with `size`, or `None` if
+    /// the day has no generator.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+/// An answer rendered for display, along with the name of the type it was
+/// computed as.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub value: String,
+    pub kind: &'static str,
+}
+
+impl Answer {
+    fn new<T: Display>(value: &T) -> Self {
+        Self {
+            value: value.to_string(),
+            kind: type_name::<T>(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// How long each step of solving a day took.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// The answers to both parts of a day. If parsing fails, both parts fail
+/// with the parse error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Run {
+    pub part_one: Result<Answer>,
+    pub part_two: Result<Answer>,
+    pub timings: Timings,
+}
+
+impl Run {
+    pub fn part(&self, part: Part) -> &Result<Answer> {
+        match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        }
+    }
+}
+
+/// The object safe form of [`Solution`], so that solutions with different
+/// answer types can be stored side by side in a registry.
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn sample(&self) -> &'static str;
+    fn input(&self) -> &'static str;
+    /// Parses the input and solves a single part.
+    fn solve(&self, part: Part, input: &str, params: &Params) -> Result<Answer>;
+    /// Parses the input and solves a single part with the reference
+    /// implementation, or returns `None` if the part doesn't have one.
+    fn solve_reference(&self, part: Part, input: &str, params: &Params) -> Option<Result<Answer>>;
+    /// Parses the input once and solves both parts, timing each step.
+    fn run(&self, input: &str, params: &Params) -> Run;
+    /// See [`Solution::generate`].
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String>;
+
+    /// The committed input of the given kind.
+    fn committed(&self, kind: InputKind) -> &'static str {
+        match kind {
+            InputKind::Sample => self.sample(),
+            InputKind::Actual => self.input(),
+        }
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn sample(&self) -> &'static str {
+        S::SAMPLE
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn solve(&self, part: Part, input: &str, params: &Params) -> Result<Answer> {
+        let input = S::parse(input)?;
+        match part {
+            Part::One => S::part_one(&input, params).map(|a| Answer::new(&a)),
+            Part::Two => S::part_two(&input, params).map(|a| Answer::new(&a)),
+        }
+    }
+
+    fn solve_reference(&self, part: Part, input: &str, params: &Params) -> Option<Result<Answer>> {
+        let input = match S::parse(input) {
+            Ok(input) => input,
+            Err(e) => return Some(Err(e)),
+        };
+        match part {
+            Part::One => S::reference_part_one(&input, params).map(|r| r.map(|a| Answer::new(&a))),
+            Part::Two => S::reference_part_two(&input, params).map(|r| r.map(|a| Answer::new(&a))),
+        }
+    }
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> Option<String> {
+        S::generate(rng, size)
+    }
+
+    fn run(&self, input: &str, params: &Params) -> Run {
+        let start = Instant::now();
+        let parsed = S::parse(input);
+        let parse = start.elapsed();
+        let input = match parsed {
+            Ok(input) => input,
+            Err(e) => {
+                return Run {
+                    part_one: Err(e.clone()),
+                    part_two: Err(e),
+                    timings: Timings {
+                        parse,
+                        ..Timings::default()
+                    },
+                };
+            }
+        };
+
+        let start = Instant::now();
+        let part_one = S::part_one(&input, params).map(|a| Answer::new(&a));
+        let part_one_time = start.elapsed();
+
+        let start = Instant::now();
+        let part_two = S::part_two(&input, params).map(|a| Answer::new(&a));
+        let part_two_time = start.elapsed();
+
+        Run {
+            part_one,
+            part_two,
+            timings: Timings {
+                parse,
+                part_one: part_one_time,
+                part_two: part_two_time,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        const SAMPLE: &'static str = "a\nbb";
+        const INPUT: &'static str = "a\nbb\nccc";
+
+        type Input<'a> = Vec<&'a str>;
+        type PartOne = usize;
+        type PartTwo = f64;
+
+        fn parse(input: &str) -> Result<Vec<&str>> {
+            if input.is_empty() {
+                return Err(Error::parse(1, 1, "expected a line"));
+            }
+            Ok(input.lines().collect())
+        }
+
+        fn part_one(input: &Vec<&str>, params: &Params) -> Result<usize> {
+            let take = params.get_or("take", usize::MAX)?;
+            Ok(input.iter().take(take).map(|l| l.len()).sum())
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        fn part_two(input: &Vec<&str>, _params: &Params) -> Result<f64> {
+            Ok(Self::part_one(input, &Params::new())? as f64 / input.len() as f64)
+        }
+    }
+
+    #[test]
+    fn test_params_get_or() {
+        let params = Params::new().with("iterations", 10);
+        assert_eq!(params.get_or("iterations", 1000).unwrap(), 10);
+        assert_eq!(params.get_or("missing", 1000).unwrap(), 1000);
+        assert_eq!(params.get::<u32>("iterations").unwrap(), Some(10));
+        assert_eq!(params.get::<u32>("missing").unwrap(), None);
+
+        let params = Params::new().with("iterations", "many");
+        assert!(params.get_or("iterations", 1000).is_err());
+    }
+
+    #[test]
+    fn test_dyn_solution() {
+        let solution: &dyn DynSolution = &Lengths;
+        assert_eq!((solution.year(), solution.day()), (2000, 1));
+
+        let input = solution.input();
+        let answer = solution.solve(Part::One, input, &Params::new()).unwrap();
+        assert_eq!(answer.value, "6");
+        assert_eq!(answer.kind, "usize");
+
+        let params = Params::new().with("take", 2);
+        let answer = solution.solve(Part::One, input, &params).unwrap();
+        assert_eq!(answer.value, "3");
+
+        let answer = solution.solve(Part::Two, input, &Params::new()).unwrap();
+        assert_eq!(answer.value, "2");
+        assert_eq!(answer.kind, "f64");
+    }
+
+    #[test]
+    fn test_dyn_solution_run() {
+        let solution: &dyn DynSolution = &Lengths;
+
+        let run = solution.run(solution.sample(), &Params::new());
+        assert_eq!(run.part(Part::One).as_ref().unwrap().value, "3");
+        assert_eq!(run.part(Part::Two).as_ref().unwrap().value, "1.5");
+
+        let run = solution.run("", &Params::new());
+        let error = Error::parse(1, 1, "expected a line");
+        assert_eq!(run.part_one, Err(error.clone()));
+        assert_eq!(run.part_two, Err(error));
+        assert_eq!(run.timings.part_one, Duration::ZERO);
+    }
+}
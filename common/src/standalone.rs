@@ -0,0 +1,78 @@
+//! The `main` shared by every day's own binary, e.g. `cargo run --bin aoc-2025-01`.
+
+use std::error::Error;
+use std::process::ExitCode;
+
+use crate::answers::{Answers, Check};
+use crate::input::{InputKind, Inputs, Origin};
+use crate::samples;
+use crate::solution::{DynSolution, Part};
+
+/// Checks both parts against the sample answers in the year's `manifest` and
+/// the day's extra samples, then solves them for the real input and prints
+/// the answers.
+///
+/// The real answers are only compared against the manifest when the committed
+/// input is used, since anyone else's input will have different answers.
+pub fn run(solution: &dyn DynSolution, manifest: &str) -> ExitCode {
+    match try_run(solution, manifest) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn try_run(solution: &dyn DynSolution, manifest: &str) -> Result<bool, Box<dyn Error>> {
+    let answers = Answers::parse(manifest)?;
+    let expected = answers.day(solution.day()).cloned().unwrap_or_default();
+    let input = Inputs::from_args().load(solution.year(), solution.day(), solution.input())?;
+    let sample = expected.get(InputKind::Sample);
+    let actual = expected
+        .get(InputKind::Actual)
+        .filter(|_| input.origin == Origin::Embedded);
+    let params = expected
+        .get(InputKind::Actual)
+        .map(|e| e.params.clone())
+        .unwrap_or_default();
+
+    let samples = samples::load(&samples::dir(solution.year(), solution.day()))?;
+
+    let mut ok = true;
+    for part in Part::ALL {
+        if let Some(sample) = sample {
+            let answer = solution.solve(part, solution.sample().trim(), &sample.params)?;
+            if let Check::Mismatch { expected } = sample.check(part, &answer.value) {
+                eprintln!("Sample {part}: expected {expected}, got {answer}");
+                ok = false;
+            }
+        }
+
+        for sample in &samples {
+            if sample.expected.answer(part).is_none() {
+                continue;
+            }
+            let answer = solution.solve(part, sample.text.trim(), &sample.expected.params)?;
+            if let Check::Mismatch { expected } = sample.expected.check(part, &answer.value) {
+                eprintln!(
+                    "Sample {} {part}: expected {expected}, got {answer}",
+                    sample.name
+                );
+                ok = false;
+            }
+        }
+
+        let answer = solution.solve(part, input.text.trim(), &params)?;
+        match actual.map(|a| a.check(part, &answer.value)) {
+            Some(Check::Mismatch { expected }) => {
+                println!("{part}: {answer} (expected {expected})");
+                ok = false;
+            }
+            _ => println!("{part}: {answer}"),
+        }
+    }
+
+    Ok(ok)
+}
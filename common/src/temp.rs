@@ -0,0 +1,54 @@
+//! Scratch directories for tests which read and write files.
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// An empty directory in the system's temporary directory, which is removed
+/// along with everything in it when dropped, even if the test fails.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Creates `aoc-<name>-<process id>`, first removing anything an earlier
+    /// run left there. Tests running at the same time need different names.
+    pub fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path)
+            .unwrap_or_else(|e| panic!("could not create {}: {e}", path.display()));
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removed_on_drop() {
+        let dir = TempDir::new("temp");
+        fs::write(dir.join("file.txt"), "text").unwrap();
+        let path = dir.path().to_path_buf();
+        assert!(path.join("file.txt").exists());
+        drop(dir);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,52 @@
+//! Facts about each Advent of Code event which every year's solutions share.
+
+/// The first year Advent of Code ran.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// The first year with only twelve puzzles rather than twenty-five.
+const SHORT_FROM: u16 = 2025;
+
+/// How many puzzles the event for `year` has, or `None` if there was no
+/// event that year.
+pub fn puzzle_count(year: u16) -> Option<u8> {
+    match year {
+        ..FIRST_YEAR => None,
+        FIRST_YEAR..SHORT_FROM => Some(25),
+        _ => Some(12),
+    }
+}
+
+/// Checks that `day` is one of the puzzles in `year`'s event.
+pub fn check_day(year: u16, day: u8) -> Result<(), String> {
+    let count = puzzle_count(year).ok_or_else(|| {
+        format!("there was no Advent of Code in {year}, it started in {FIRST_YEAR}")
+    })?;
+    if !(1..=count).contains(&day) {
+        return Err(format!("{year} has days 1 to {count}, not {day}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle_count() {
+        assert_eq!(puzzle_count(2014), None);
+        assert_eq!(puzzle_count(2015), Some(25));
+        assert_eq!(puzzle_count(2024), Some(25));
+        assert_eq!(puzzle_count(2025), Some(12));
+    }
+
+    #[test]
+    fn test_check_day() {
+        assert_eq!(check_day(2024, 25), Ok(()));
+        assert_eq!(
+            check_day(2025, 13),
+            Err("2025 has days 1 to 12, not 13".to_string())
+        );
+        assert!(check_day(2025, 0).is_err());
+        assert!(check_day(2014, 1).is_err());
+    }
+}
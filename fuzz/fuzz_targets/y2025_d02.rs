@@ -0,0 +1,11 @@
+#![no_main]
+
+use std::sync::LazyLock;
+
+use aoc_common::fuzz;
+use aoc_common::solution::Params;
+
+static PARAMS: LazyLock<Params> =
+    LazyLock::new(|| fuzz::params(include_str!("../../2025/answers.toml"), 2).unwrap());
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| fuzz::solve(&aoc_2025_02::Day02, data, &PARAMS));
@@ -0,0 +1,122 @@
+//! Generates a test for every answer in the workspace: each part of each
+//! day's sample and committed input in `YEAR/answers.toml`, and of each file
+//! in its `samples` directory. They are included by `verify::generated`.
+
+use std::error::Error;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use aoc_common::answers::Answers;
+use aoc_common::input::InputKind;
+use aoc_common::samples;
+use aoc_common::solution::Part;
+
+fn part_name(part: Part) -> &'static str {
+    match part {
+        Part::One => "part_one",
+        Part::Two => "part_two",
+    }
+}
+
+fn part_path(part: Part) -> &'static str {
+    match part {
+        Part::One => "Part::One",
+        Part::Two => "Part::Two",
+    }
+}
+
+/// A test name from a sample's file name, e.g. `95-115.txt` to `95_115`.
+fn identifier(name: &str) -> String {
+    let stem = name.strip_suffix(".txt").unwrap_or(name);
+    stem.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn generate(root: &Path) -> Result<String, Box<dyn Error>> {
+    let mut years: Vec<(u16, String)> = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let path = entry?.path();
+        let Some(year) = path
+            .file_name()
+            .and_then(|n| n.to_str()?.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        let manifest = path.join("answers.toml");
+        if manifest.exists() {
+            // Covers the manifest and every day's samples.
+            println!("cargo::rerun-if-changed={}", path.display());
+            years.push((year, fs::read_to_string(&manifest)?));
+        }
+    }
+    years.sort();
+
+    let mut code = String::new();
+    for (year, manifest) in years {
+        let answers = Answers::parse(&manifest).map_err(|e| format!("{year}/answers.toml: {e}"))?;
+        writeln!(code, "mod y{year} {{")?;
+        for (day, expected) in answers.days() {
+            writeln!(code, "    mod d{day:02} {{")?;
+            writeln!(code, "        use super::super::*;")?;
+            for kind in InputKind::ALL {
+                let Some(expected) = expected.get(kind) else {
+                    continue;
+                };
+                for part in Part::ALL
+                    .into_iter()
+                    .filter(|&p| expected.answer(p).is_some())
+                {
+                    let (name, variant) = match kind {
+                        InputKind::Sample => ("sample", "Sample"),
+                        InputKind::Actual => ("actual", "Actual"),
+                    };
+                    writeln!(
+                        code,
+                        "        #[test]\n        fn {name}_{}() {{\n            \
+                         check_committed({year}, {day}, InputKind::{variant}, {});\n        }}",
+                        part_name(part),
+                        part_path(part),
+                    )?;
+                }
+            }
+
+            for sample in samples::load(&samples::dir(year, day))? {
+                for part in Part::ALL
+                    .into_iter()
+                    .filter(|&p| sample.expected.answer(p).is_some())
+                {
+                    writeln!(
+                        code,
+                        "        #[test]\n        fn samples_{}_{}() {{\n            \
+                         check_sample({year}, {day}, {:?}, {});\n        }}",
+                        identifier(&sample.name),
+                        part_name(part),
+                        sample.name,
+                        part_path(part),
+                    )?;
+                }
+            }
+            writeln!(code, "    }}")?;
+        }
+        writeln!(code, "}}")?;
+    }
+    Ok(code)
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is inside the workspace");
+    let code = generate(root)?;
+    let out = Path::new(&std::env::var("OUT_DIR")?).join("generated_tests.rs");
+    fs::write(out, code)?;
+    Ok(())
+}
@@ -0,0 +1,296 @@
+//! Runs days repeatedly to measure each step, and compares the measurements
+//! against a baseline saved by an earlier run.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_common::solution::{DynSolution, Params, Timings};
+use serde::{Deserialize, Serialize};
+
+/// A step of solving a day which is timed on its own.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::PartOne, Phase::PartTwo];
+
+    fn time(self, timings: &Timings) -> Duration {
+        match self {
+            Phase::Parse => timings.parse,
+            Phase::PartOne => timings.part_one,
+            Phase::PartTwo => timings.part_two,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "Parse",
+            Phase::PartOne => "Part One",
+            Phase::PartTwo => "Part Two",
+        })
+    }
+}
+
+/// Summary of the times taken by repeated runs of one step, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Stats {
+    pub mean: u64,
+    pub median: u64,
+    pub min: u64,
+}
+
+impl Stats {
+    /// Summarises `samples`, or returns `None` if there aren't any.
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut nanos: Vec<u64> = samples
+            .iter()
+            .map(|d| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX))
+            .collect();
+        nanos.sort_unstable();
+        let len = nanos.len();
+        if len == 0 {
+            return None;
+        }
+
+        let sum: u128 = nanos.iter().map(|&n| u128::from(n)).sum();
+        let mean = u64::try_from(sum / len as u128).unwrap_or(u64::MAX);
+        let median = if len.is_multiple_of(2) {
+            nanos[len / 2 - 1] / 2 + nanos[len / 2] / 2
+        } else {
+            nanos[len / 2]
+        };
+        Some(Stats {
+            mean,
+            median,
+            min: nanos[0],
+        })
+    }
+}
+
+/// The stats for each step of one day.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayStats {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl DayStats {
+    pub fn get(&self, phase: Phase) -> &Stats {
+        match phase {
+            Phase::Parse => &self.parse,
+            Phase::PartOne => &self.part_one,
+            Phase::PartTwo => &self.part_two,
+        }
+    }
+}
+
+/// Solves the day `runs` times, failing if either part has no answer.
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    params: &Params,
+    runs: usize,
+) -> Result<DayStats, String> {
+    let mut timings = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let run = solution.run(input, params);
+        if let Err(e) = run.part_one.and(run.part_two) {
+            return Err(e.to_string());
+        }
+        timings.push(run.timings);
+    }
+
+    let stats = |phase: Phase| {
+        let samples: Vec<Duration> = timings.iter().map(|t| phase.time(t)).collect();
+        Stats::from_samples(&samples).ok_or_else(|| "no runs were made".to_string())
+    };
+    Ok(DayStats {
+        parse: stats(Phase::Parse)?,
+        part_one: stats(Phase::PartOne)?,
+        part_two: stats(Phase::PartTwo)?,
+    })
+}
+
+/// Stats saved by an earlier benchmark, keyed by year and then by
+/// two-digit day, e.g. `[2025.01.parse]`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, DayStats>>);
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Reads the baseline at `path`, or an empty one if the file doesn't
+    /// exist yet.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text)
+                .map_err(|e| format!("invalid baseline {}: {e}", path.display()).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display()).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)
+            .map_err(|e| format!("could not write {}: {e}", path.display()).into())
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&DayStats> {
+        self.0.get(&year.to_string())?.get(&format!("{day:02}"))
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, stats: DayStats) {
+        self.0
+            .entry(year.to_string())
+            .or_default()
+            .insert(format!("{day:02}"), stats);
+    }
+}
+
+/// How the median time of a step moved relative to the baseline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Change {
+    /// Positive when the step got slower.
+    pub percent: f64,
+    pub regression: bool,
+}
+
+impl Change {
+    /// Compares medians, flagging anything more than `threshold` percent
+    /// slower as a regression.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn between(baseline: &Stats, current: &Stats, threshold: f64) -> Change {
+        let (before, after) = (baseline.median as f64, current.median as f64);
+        let percent = if before == 0.0 {
+            0.0
+        } else {
+            (after - before) / before * 100.0
+        };
+        Change {
+            percent,
+            regression: percent > threshold,
+        }
+    }
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+/// Prints the stats of each step of a day, along with how they compare to
+/// the baseline. Returns whether any step regressed.
+pub fn print_bench(
+    year: u16,
+    day: u8,
+    stats: &DayStats,
+    baseline: Option<&DayStats>,
+    threshold: f64,
+) -> bool {
+    println!("{year} Day {day:02}:");
+    let mut regressed = false;
+    for phase in Phase::ALL {
+        let current = stats.get(phase);
+        let change = baseline.map(|b| Change::between(b.get(phase), current, threshold));
+        let comparison = match change {
+            Some(Change {
+                percent,
+                regression: true,
+            }) => {
+                regressed = true;
+                format!("  REGRESSION {percent:+.1}%")
+            }
+            Some(Change { percent, .. }) => format!("  {percent:+.1}%"),
+            None => String::new(),
+        };
+        println!(
+            "  {phase:<8}  mean {:>10}  median {:>10}  min {:>10}{comparison}",
+            format_nanos(current.mean),
+            format_nanos(current.median),
+            format_nanos(current.min),
+        );
+    }
+    regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            mean: median,
+            median,
+            min: median,
+        }
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [5, 1, 3].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Some(Stats {
+                mean: 3,
+                median: 3,
+                min: 1
+            })
+        );
+
+        let samples = [8, 2, 4, 10].map(Duration::from_nanos);
+        assert_eq!(
+            Stats::from_samples(&samples),
+            Some(Stats {
+                mean: 6,
+                median: 6,
+                min: 2
+            })
+        );
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        let day = DayStats {
+            parse: stats(10),
+            part_one: stats(200),
+            part_two: stats(3000),
+        };
+        baseline.insert(2025, 7, day);
+
+        let text = toml::to_string(&baseline).unwrap();
+        assert!(text.contains("[2025.07.parse]"), "{text}");
+
+        let parsed = Baseline::parse(&text).unwrap();
+        assert_eq!(parsed.get(2025, 7), Some(&day));
+        assert_eq!(parsed.get(2025, 8), None);
+    }
+
+    #[test]
+    fn test_change_between() {
+        let change = Change::between(&stats(100), &stats(125), 10.0);
+        assert!(change.regression);
+        assert!((change.percent - 25.0).abs() < f64::EPSILON);
+
+        assert!(!Change::between(&stats(100), &stats(105), 10.0).regression);
+        assert!(!Change::between(&stats(100), &stats(50), 10.0).regression);
+        assert!(!Change::between(&stats(0), &stats(50), 10.0).regression);
+    }
+}
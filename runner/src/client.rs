@@ -0,0 +1,90 @@
+//! Talks to adventofcode.com on behalf of the logged in user.
+
+use std::error::Error;
+use std::time::Duration;
+
+use aoc_common::solution::Part;
+use ureq::Agent;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the site's operators, as they ask automated tools
+/// to do.
+const USER_AGENT: &str = "github.com/BenShelton/advent-of-code-rust";
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    /// A client for the site at `base_url`. Requests fail if there is no
+    /// `session` token.
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.filter(|s| !s.is_empty()),
+            agent,
+        }
+    }
+
+    fn cookie(&self) -> Result<String, Box<dyn Error>> {
+        let session = self.session.as_ref().ok_or(
+            "no session token, set AOC_SESSION or `session` in aoc.toml to the `session` \
+             cookie of a logged in browser",
+        )?;
+        Ok(format!("session={session}"))
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let mut response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie()?)
+            .call()
+            .map_err(|e| format!("could not download {url}: {e}"))?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        match status {
+            200 => Ok(body),
+            404 => Err(format!("the input for {year} day {day} isn't available yet").into()),
+            400 => Err("the session token was rejected, it may have expired".into()),
+            _ => Err(format!("{url} responded with {status}: {}", body.trim()).into()),
+        }
+    }
+
+    /// Posts an answer for one part of a day, returning the page the site
+    /// replies with.
+    pub fn answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, Box<dyn Error>> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie()?)
+            .send_form([("level", level.as_str()), ("answer", answer)])
+            .map_err(|e| format!("could not post to {url}: {e}"))?;
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        match status {
+            200 => Ok(body),
+            400 => Err("the session token was rejected, it may have expired".into()),
+            _ => Err(format!("{url} responded with {status}: {}", body.trim()).into()),
+        }
+    }
+}
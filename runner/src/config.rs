@@ -0,0 +1,66 @@
+//! Settings which belong to whoever is running the solutions rather than to
+//! the repository, read from an uncommitted `aoc.toml` at the workspace root.
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."
+//! ```
+
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde::Deserialize;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Overrides the `session` in the config file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The `session` cookie of a browser logged in to adventofcode.com.
+    pub session: Option<String>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Reads `aoc.toml` from the workspace `root`, or returns the defaults if
+    /// there isn't one.
+    pub fn load(root: &Path) -> Result<Self, Box<dyn Error>> {
+        let path = root.join(CONFIG_FILE);
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {e}", path.display()).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display()).into()),
+        }
+    }
+
+    /// The session token from `$AOC_SESSION`, falling back to the config file.
+    pub fn session(&self) -> Option<String> {
+        std::env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(
+            Config::parse("session = \"abc\"").unwrap().session,
+            Some("abc".to_string())
+        );
+        assert!(Config::parse("sesion = \"abc\"").is_err());
+    }
+}
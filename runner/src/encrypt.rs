@@ -0,0 +1,150 @@
+//! Encrypts the committed inputs in place, so that they can be pushed to a
+//! public repository.
+
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use aoc_common::encryption::{Key, decrypt, encrypt, is_encrypted};
+
+/// What happened to one input file.
+#[derive(Debug, PartialEq)]
+pub enum Encrypted {
+    Encrypted,
+    AlreadyEncrypted,
+    /// There is no input yet.
+    Empty,
+}
+
+/// What happened when filling in a committed input.
+#[derive(Debug, PartialEq)]
+pub enum Filled {
+    Filled,
+    /// The day doesn't exist, or already has an input.
+    Skipped,
+    /// There is no key, so the input can't be committed without showing it.
+    NoKey(String),
+}
+
+/// The committed input of a day, under `root`, the workspace directory.
+pub fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("{year}/{day:02}/src/data-actual.txt"))
+}
+
+/// Reads the key at `path`, generating and saving a new one if there isn't a
+/// file there yet. Returns whether the key is new.
+pub fn load_or_create_key(path: &Path) -> Result<(Key, bool), Box<dyn Error>> {
+    match fs::metadata(path) {
+        Ok(_) => Ok((Key::load(path)?, false)),
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            let key = Key::generate();
+            fs::write(path, key.to_hex() + "\n")
+                .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+            Ok((key, true))
+        }
+        Err(e) => Err(format!("could not read {}: {e}", path.display()).into()),
+    }
+}
+
+/// Writes `text`, encrypted with the key at `key_path`, into the committed
+/// input at `path`, but only if that file exists and is still empty. A
+/// missing key is reported rather than writing the input in plain text.
+pub fn fill_input(key_path: &Path, path: &Path, text: &str) -> Result<Filled, Box<dyn Error>> {
+    match fs::read_to_string(path) {
+        Ok(existing) if existing.trim().is_empty() => {}
+        Ok(_) => return Ok(Filled::Skipped),
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Filled::Skipped),
+        Err(e) => return Err(format!("could not read {}: {e}", path.display()).into()),
+    }
+    let key = match Key::load(key_path) {
+        Ok(key) => key,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Filled::NoKey(e.to_string())),
+        Err(e) => return Err(e.into()),
+    };
+    fs::write(path, encrypt(&key, text))
+        .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    Ok(Filled::Filled)
+}
+
+/// Replaces the file at `path` with its encrypted contents, checking that it
+/// decrypts back to the original before writing it.
+pub fn encrypt_file(key: &Key, path: &Path) -> Result<Encrypted, Box<dyn Error>> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    if is_encrypted(&text) {
+        return Ok(Encrypted::AlreadyEncrypted);
+    }
+    if text.trim().is_empty() {
+        return Ok(Encrypted::Empty);
+    }
+
+    let encrypted = encrypt(key, &text);
+    if decrypt(key, &encrypted)? != text {
+        return Err(format!("{} did not survive encryption", path.display()).into());
+    }
+    fs::write(path, encrypted).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    Ok(Encrypted::Encrypted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::temp::TempDir;
+
+    #[test]
+    fn test_encrypt_file() {
+        let dir = TempDir::new("encrypt");
+
+        let key_path = dir.join("aoc.key");
+        let (key, created) = load_or_create_key(&key_path).unwrap();
+        assert!(created);
+        let (loaded, created) = load_or_create_key(&key_path).unwrap();
+        assert!(!created);
+        assert_eq!(loaded.to_hex(), key.to_hex());
+
+        let input = dir.join("data-actual.txt");
+        fs::write(&input, "L68\nL30\n").unwrap();
+        assert_eq!(encrypt_file(&key, &input).unwrap(), Encrypted::Encrypted);
+        let text = fs::read_to_string(&input).unwrap();
+        assert_eq!(decrypt(&key, &text).unwrap(), "L68\nL30\n");
+        assert_eq!(
+            encrypt_file(&key, &input).unwrap(),
+            Encrypted::AlreadyEncrypted
+        );
+
+        fs::write(&input, "\n").unwrap();
+        assert_eq!(encrypt_file(&key, &input).unwrap(), Encrypted::Empty);
+    }
+
+    #[test]
+    fn test_fill_input() {
+        let dir = TempDir::new("encrypt-fill");
+        let key_path = dir.join("aoc.key");
+        let input = dir.join("data-actual.txt");
+        fs::write(&input, "").unwrap();
+
+        let filled = fill_input(&key_path, &input, "L68\n").unwrap();
+        assert!(matches!(filled, Filled::NoKey(_)));
+        assert_eq!(fs::read_to_string(&input).unwrap(), "");
+
+        let (key, _) = load_or_create_key(&key_path).unwrap();
+        assert_eq!(
+            fill_input(&key_path, &input, "L68\n").unwrap(),
+            Filled::Filled
+        );
+        let text = fs::read_to_string(&input).unwrap();
+        assert_eq!(decrypt(&key, &text).unwrap(), "L68\n");
+
+        assert_eq!(
+            fill_input(&key_path, &input, "R1\n").unwrap(),
+            Filled::Skipped
+        );
+        let missing = dir.join("missing.txt");
+        assert_eq!(
+            fill_input(&key_path, &missing, "R1\n").unwrap(),
+            Filled::Skipped
+        );
+        assert!(!missing.exists());
+    }
+}
@@ -0,0 +1,112 @@
+//! Downloads puzzle inputs into a local cache, so that each one is only ever
+//! requested once.
+
+use std::error::Error;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use aoc_common::input::Inputs;
+use aoc_common::year::check_day;
+
+use crate::client::Client;
+
+/// The cache directory, relative to the workspace root, used when no inputs
+/// directory is given.
+pub const DEFAULT_CACHE: &str = "inputs";
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    /// The input was already in the cache, so nothing was requested.
+    Cached,
+    Downloaded,
+}
+
+/// The day's input from `cache`, laid out as `<cache>/<year>/<day>.txt`,
+/// downloading it first if it isn't there yet.
+pub fn fetch_input(
+    client: &Client,
+    cache: &Path,
+    year: u16,
+    day: u8,
+) -> Result<(String, Fetched), Box<dyn Error>> {
+    check_day(year, day)?;
+    let path = Inputs::path_in_dir(cache, year, day);
+    match fs::read_to_string(&path) {
+        Ok(text) => return Ok((text, Fetched::Cached)),
+        Err(e) if e.kind() == ErrorKind::NotFound => {}
+        Err(e) => return Err(format!("could not read {}: {e}", path.display()).into()),
+    }
+
+    let text = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    // Written alongside and then renamed into place, so that an interrupted
+    // write can't leave a truncated input which looks like it's cached.
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, &text)
+        .and_then(|()| fs::rename(&partial, &path))
+        .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    Ok((text, Fetched::Downloaded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+    use aoc_common::temp::TempDir;
+
+    #[test]
+    fn test_fetch_input_is_cached() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/2025/day/7/input" => (200, "..S..\n".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let client = Client::new(&server.url, Some("secret".to_string()));
+        let cache = TempDir::new("fetch-cached");
+
+        let (text, fetched) = fetch_input(&client, &cache, 2025, 7).unwrap();
+        assert_eq!((text.as_str(), fetched), ("..S..\n", Fetched::Downloaded));
+        assert_eq!(
+            fs::read_to_string(cache.join("2025/07.txt")).unwrap(),
+            "..S..\n"
+        );
+        assert!(!cache.join("2025/07.txt.part").exists());
+
+        let (text, fetched) = fetch_input(&client, &cache, 2025, 7).unwrap();
+        assert_eq!((text.as_str(), fetched), ("..S..\n", Fetched::Cached));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert!(requests[0].header("User-Agent").is_some());
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/2025/day/1/input" => (400, "Please log in to get your puzzle input.".to_string()),
+            _ => (404, "Not Found".to_string()),
+        });
+        let cache = TempDir::new("fetch-errors");
+
+        let client = Client::new(&server.url, Some("expired".to_string()));
+        let error = fetch_input(&client, &cache, 2025, 1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the session token was rejected, it may have expired"
+        );
+        let error = fetch_input(&client, &cache, 2025, 12).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the input for 2025 day 12 isn't available yet"
+        );
+        assert!(!cache.join("2025").exists());
+
+        let client = Client::new(&server.url, None);
+        assert!(fetch_input(&client, &cache, 2025, 1).is_err());
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -0,0 +1,17 @@
+//! Runs any of the registered Advent of Code solutions from a single binary.
+
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod encrypt;
+pub mod fetch;
+pub mod pool;
+pub mod registry;
+pub mod run;
+pub mod scaffold;
+pub mod select;
+pub mod submit;
+#[cfg(test)]
+mod test_server;
+pub mod verify;
+pub mod watch;
@@ -0,0 +1,545 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::error::Error;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use aoc::bench::{Baseline, bench_day, print_bench};
+use aoc::client::{BASE_URL, Client};
+use aoc::config::Config;
+use aoc::encrypt::{Encrypted, Filled, encrypt_file, fill_input, input_path, load_or_create_key};
+use aoc::fetch::{DEFAULT_CACHE, Fetched, fetch_input};
+use aoc::pool::{PoolOptions, capture_panics, run_days};
+use aoc::registry::{self, YEARS};
+use aoc::run::{DayStatus, ignore_broken_pipe, part_records, print_report, print_summary, run_day};
+use aoc::scaffold::new_day;
+use aoc::select::{Selection, parse_days, parse_part};
+use aoc::submit::{GUESSES_FILE, Reply, submit_answer};
+use aoc::verify::{Verdict, print_verification, verify_day};
+use aoc::watch::watch;
+use aoc_common::encryption::Key;
+use aoc_common::input::{InputKind, Inputs};
+use aoc_common::random::Rng;
+use aoc_common::samples;
+use aoc_common::solution::{DynSolution, Part};
+use aoc_common::year::{check_day, puzzle_count};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every day, a whole year, a single day or a range of days
+    Run {
+        #[command(flatten)]
+        select: SelectArgs,
+        #[command(flatten)]
+        inputs: InputArgs,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// Check the answers for the committed inputs against `YEAR/answers.toml`, and those for
+    /// the extra samples in `YEAR/DAY/samples`
+    Verify {
+        #[command(flatten)]
+        select: SelectArgs,
+    },
+    /// Time parsing and each part over repeated runs
+    Bench {
+        #[command(flatten)]
+        select: SelectArgs,
+        #[command(flatten)]
+        inputs: InputArgs,
+        #[command(flatten)]
+        bench: BenchArgs,
+    },
+    /// List the years with solutions and how many of their days are solved
+    Years,
+    /// Download a day's input, unless it has been downloaded before
+    Fetch {
+        year: u16,
+        day: u8,
+        /// Keep inputs in `<DIR>/<year>/<day>.txt` [default: $AOC_INPUTS or inputs]
+        #[arg(long, value_name = "DIR")]
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Solve one part of a day and submit the answer, unless it is known to be wrong
+    Submit {
+        year: u16,
+        day: u8,
+        /// `1` or `2`
+        #[arg(value_parser = parse_part)]
+        part: Part,
+        /// Submit this answer instead of solving the part
+        #[arg(long)]
+        answer: Option<String>,
+        #[command(flatten)]
+        inputs: InputArgs,
+    },
+    /// Create the crate for a new day from the template
+    New { year: u16, day: u8 },
+    /// Print a random but valid input for a day
+    Generate {
+        year: u16,
+        day: u8,
+        /// How big an input to make, in the day's own terms, e.g. lines or grid width
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// Seed the input, so that it can be made again [default: from the clock]
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Re-run a day, with its sample checks, whenever its files change
+    Watch {
+        year: u16,
+        day: u8,
+        /// Build with optimisations, for realistic timings
+        #[arg(long)]
+        release: bool,
+    },
+    /// Encrypt the committed real inputs, creating the key if there isn't one
+    Encrypt {
+        #[command(flatten)]
+        select: SelectArgs,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// Each day as it finishes, then a summary table
+    Text,
+    /// A JSON array with one object per part, printed once every day has run
+    Json,
+}
+
+#[derive(Args)]
+struct SelectArgs {
+    /// Only include days from this year
+    year: Option<u16>,
+    /// A single day (`7`) or a range of days (`3-5` or `3..=5`, or `3..6` without day 6)
+    #[arg(requires = "year", value_parser = parse_days)]
+    days: Option<RangeInclusive<u8>>,
+}
+
+impl SelectArgs {
+    fn solutions(self) -> Result<Vec<&'static dyn DynSolution>, Box<dyn Error>> {
+        let selection = Selection {
+            year: self.year,
+            days: self.days,
+        };
+        selection.check()?;
+        let solutions: Vec<_> = registry::solutions()
+            .filter(|s| selection.matches(s.year(), s.day()))
+            .collect();
+        if solutions.is_empty() {
+            return Err(match selection.year {
+                Some(year) if registry::year(year).is_none() => {
+                    format!("there are no solutions for {year} yet").into()
+                }
+                _ => "no solutions match the selection".into(),
+            });
+        }
+        Ok(solutions)
+    }
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file instead, or `-` for stdin (single day only)
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// Look for inputs in `<DIR>/<year>/<day>.txt` [default: $AOC_INPUTS]
+    #[arg(long, value_name = "DIR")]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct PoolArgs {
+    /// How many days to run at once [default: the number of CPUs]
+    #[arg(long, short, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+    /// Give up on any day which takes longer than this
+    #[arg(long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+}
+
+impl PoolArgs {
+    fn options(&self) -> PoolOptions {
+        let cpus = thread::available_parallelism().map_or(1, usize::from);
+        PoolOptions {
+            jobs: self.jobs.map_or(cpus, usize::from),
+            timeout: self.timeout,
+        }
+    }
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value.parse().map_err(|e| format!("{e}"))?;
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|d| !d.is_zero())
+        .ok_or_else(|| "expected a positive number of seconds".to_string())
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// How many times to solve each day
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Save the results to this file, keeping any other days already in it
+    #[arg(long, value_name = "PATH")]
+    save: Option<PathBuf>,
+    /// Compare the results against a file written by `--save`
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+    /// Flag a step as a regression when its median is this many percent slower
+    #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+    threshold: f64,
+}
+
+impl InputArgs {
+    fn into_inputs(self) -> Inputs {
+        let env = Inputs::from_env();
+        Inputs {
+            file: self.input,
+            dir: self.inputs_dir.or(env.dir),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            select,
+            inputs,
+            format,
+            pool,
+        } => run(select, inputs.into_inputs(), format, &pool),
+        Command::Verify { select } => verify(select),
+        Command::Bench {
+            select,
+            inputs,
+            bench: args,
+        } => bench(select, &inputs.into_inputs(), &args),
+        Command::Years => years(),
+        Command::Fetch {
+            year,
+            day,
+            inputs_dir,
+        } => fetch(year, day, inputs_dir),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            inputs,
+        } => submit(year, day, part, answer, &inputs.into_inputs()),
+        Command::New { year, day } => new(year, day),
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => generate(year, day, size, seed),
+        Command::Watch { year, day, release } => watch_day(year, day, release),
+        Command::Encrypt { select } => encrypt(select),
+    };
+
+    result.unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        ExitCode::FAILURE
+    })
+}
+
+fn run(
+    select: SelectArgs,
+    inputs: Inputs,
+    format: Format,
+    pool: &PoolArgs,
+) -> Result<ExitCode, Box<dyn Error>> {
+    let solutions = select.solutions()?;
+    if inputs.file.is_some() && solutions.len() > 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+    let mut answers = BTreeMap::new();
+    for solution in &solutions {
+        if let Entry::Vacant(entry) = answers.entry(solution.year()) {
+            entry.insert(registry::answers(solution.year())?);
+        }
+    }
+
+    capture_panics();
+    let start = Instant::now();
+    let stdout = io::stdout();
+    let mut printed = Ok(());
+    let reports = run_days(
+        &solutions,
+        &pool.options(),
+        move |solution| run_day(solution, &inputs, &answers[&solution.year()]),
+        |report| {
+            if format == Format::Text && printed.is_ok() {
+                printed = print_report(&mut stdout.lock(), report);
+            }
+        },
+    );
+
+    let mut out = stdout.lock();
+    let printed = printed.and_then(|()| match format {
+        Format::Text => {
+            writeln!(out)?;
+            print_summary(&mut out, &reports, start.elapsed())
+        }
+        Format::Json => {
+            let records: Vec<_> = reports.iter().flat_map(part_records).collect();
+            serde_json::to_writer_pretty(&mut out, &records)?;
+            writeln!(out)
+        }
+    });
+    ignore_broken_pipe(printed)?;
+
+    Ok(exit_code(
+        reports.iter().all(|r| r.status() == DayStatus::Passed),
+    ))
+}
+
+fn verify(select: SelectArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solution in select.solutions()? {
+        let answers = registry::answers(solution.year())?;
+        let samples = samples::load(&samples::dir(solution.year(), solution.day()))?;
+        let verifications = verify_day(solution, &answers, &samples);
+        print_verification(solution.year(), solution.day(), &verifications);
+        for verification in verifications {
+            match verification.verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Mismatch { .. } | Verdict::Failed(_) => failed += 1,
+                Verdict::Missing => missing += 1,
+            }
+        }
+    }
+    println!("\n{passed} passed, {failed} failed, {missing} without an expected answer");
+
+    Ok(exit_code(failed == 0))
+}
+
+fn bench(
+    select: SelectArgs,
+    inputs: &Inputs,
+    args: &BenchArgs,
+) -> Result<ExitCode, Box<dyn Error>> {
+    let solutions = select.solutions()?;
+    if inputs.file.is_some() && solutions.len() > 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+    let baseline = match &args.baseline {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let mut saved = match &args.save {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+
+    let (mut failed, mut regressed) = (0, 0);
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let input = inputs.load(year, day, solution.input())?;
+        let params = registry::answers(year)?.params(day, InputKind::Actual);
+        match bench_day(solution, input.text.trim(), &params, args.runs as usize) {
+            Ok(stats) => {
+                let before = baseline.as_ref().and_then(|b| b.get(year, day));
+                if print_bench(year, day, &stats, before, args.threshold) {
+                    regressed += 1;
+                }
+                if let Some(saved) = &mut saved {
+                    saved.insert(year, day, stats);
+                }
+            }
+            Err(e) => {
+                println!("{year} Day {day:02} failed: {e}");
+                failed += 1;
+            }
+        }
+    }
+
+    if let (Some(path), Some(saved)) = (&args.save, &saved) {
+        saved.save(path)?;
+        println!("\nSaved the results to {}", path.display());
+    }
+    if baseline.is_some() {
+        println!(
+            "\n{regressed} days regressed by more than {}%",
+            args.threshold
+        );
+    }
+
+    Ok(exit_code(failed == 0 && regressed == 0))
+}
+
+#[allow(clippy::unnecessary_wraps)]
+fn years() -> Result<ExitCode, Box<dyn Error>> {
+    for year in YEARS {
+        let count = puzzle_count(year.year).unwrap_or_default();
+        let days: Vec<String> = year.solutions.iter().map(|s| s.day().to_string()).collect();
+        println!(
+            "{}: {}/{count} days solved ({})",
+            year.year,
+            year.solutions.len(),
+            days.join(", ")
+        );
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// The workspace directory, which the runner is always built from.
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner is inside the workspace")
+}
+
+fn fetch(year: u16, day: u8, inputs_dir: Option<PathBuf>) -> Result<ExitCode, Box<dyn Error>> {
+    let root = workspace_root();
+    let cache = inputs_dir
+        .or(Inputs::from_env().dir)
+        .unwrap_or_else(|| root.join(DEFAULT_CACHE));
+    let client = Client::new(BASE_URL, Config::load(root)?.session());
+
+    let (text, fetched) = fetch_input(&client, &cache, year, day)?;
+    let path = Inputs::path_in_dir(&cache, year, day);
+    match fetched {
+        Fetched::Cached => println!("Already have {}", path.display()),
+        Fetched::Downloaded => println!("Downloaded {}", path.display()),
+    }
+    let data = input_path(root, year, day);
+    match fill_input(&Key::default_path(), &data, &text)? {
+        Filled::Filled => println!("Encrypted it into {}", data.display()),
+        Filled::Skipped => {}
+        Filled::NoKey(reason) => println!(
+            "Not copying it to {} without a key to encrypt it ({reason}), run `aoc encrypt` to create one",
+            data.display()
+        ),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    inputs: &Inputs,
+) -> Result<ExitCode, Box<dyn Error>> {
+    check_day(year, day)?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = registry::find(year, day)
+                .ok_or_else(|| format!("there is no solution for {year} day {day} yet"))?;
+            let input = inputs.load(year, day, solution.input())?;
+            let params = registry::answers(year)?.params(day, InputKind::Actual);
+            solution.solve(part, input.text.trim(), &params)?.value
+        }
+    };
+
+    let root = workspace_root();
+    let client = Client::new(BASE_URL, Config::load(root)?.session());
+    println!("Submitting {answer} for {year} Day {day:02} {part}");
+    let reply = submit_answer(&client, &root.join(GUESSES_FILE), year, day, part, &answer)?;
+    println!("{reply}");
+
+    Ok(exit_code(reply == Reply::Correct))
+}
+
+fn new(year: u16, day: u8) -> Result<ExitCode, Box<dyn Error>> {
+    let dir = new_day(workspace_root(), year, day)?;
+    println!("Created aoc-{year}-{day:02} in {}", dir.display());
+    println!(
+        "Add it to runner/Cargo.toml and runner/src/registry.rs to run it with `aoc`, \
+         and its answers to {year}/answers.toml once solved"
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn encrypt(select: SelectArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let solutions = select.solutions()?;
+    let key_path = Key::default_path();
+    let (key, created) = load_or_create_key(&key_path)?;
+    if created {
+        println!(
+            "Created a new key in {}, keep a copy of it somewhere safe",
+            key_path.display()
+        );
+    }
+
+    let root = workspace_root();
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let path = input_path(root, year, day);
+        match encrypt_file(&key, &path)? {
+            Encrypted::Encrypted => println!("Encrypted {}", path.display()),
+            Encrypted::AlreadyEncrypted | Encrypted::Empty => {}
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn generate(
+    year: u16,
+    day: u8,
+    size: usize,
+    seed: Option<u64>,
+) -> Result<ExitCode, Box<dyn Error>> {
+    check_day(year, day)?;
+    let solution = registry::find(year, day)
+        .ok_or_else(|| format!("there is no solution for {year} day {day} yet"))?;
+    let seed = seed.unwrap_or_else(|| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        u64::try_from(now.as_nanos() % u128::from(u64::MAX)).unwrap_or_default()
+    });
+    let input = solution
+        .generate(&mut Rng::new(seed), size)
+        .ok_or_else(|| format!("{year} day {day} has no input generator"))?;
+    eprintln!("Generated {year} Day {day:02} with --size {size} --seed {seed}");
+    print!("{input}");
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn watch_day(year: u16, day: u8, release: bool) -> Result<ExitCode, Box<dyn Error>> {
+    check_day(year, day)?;
+    if registry::find(year, day).is_none() {
+        return Err(format!("there is no solution for {year} day {day} yet").into());
+    }
+    watch(workspace_root(), year, day, release)?;
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
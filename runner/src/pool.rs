@@ -0,0 +1,254 @@
+//! Runs days concurrently, turning a panic or an overrun in one day into a
+//! report rather than letting it take down the others.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::solution::DynSolution;
+
+use crate::run::{Interruption, Report};
+
+thread_local! {
+    /// The message and location of the last panic on this thread, recorded by
+    /// the hook installed by [`capture_panics`].
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+
+    /// Whether this thread is running a day, set by [`run_one`].
+    static IN_DAY: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that a panic while running a day is quietly
+/// recorded, to be shown in the day's report, instead of printing a
+/// backtrace. Panics anywhere else still go to the previous hook.
+pub fn capture_panics() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !IN_DAY.get() {
+            previous(info);
+            return;
+        }
+        let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+        let message = match info.location() {
+            Some(location) => format!("{message} at {location}"),
+            None => message.to_string(),
+        };
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+    }));
+}
+
+/// The message of the panic which unwound with `payload`, preferring the
+/// one recorded by the hook, which knows where it happened.
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> String {
+    LAST_PANIC
+        .with(|last| last.borrow_mut().take())
+        .or_else(|| payload.downcast_ref::<&str>().map(ToString::to_string))
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+pub struct PoolOptions {
+    /// How many days to run at once.
+    pub jobs: usize,
+    /// How long each day may take before it is reported as timed out.
+    pub timeout: Option<Duration>,
+}
+
+/// Runs `job` for every solution on a pool of `options.jobs` threads, calling
+/// `on_finish` with each report as soon as it is ready. The reports are
+/// returned in the same order as `solutions`.
+///
+/// Each day runs on a thread of its own so that its worker can stop waiting
+/// for it. A day which times out is left running in the background, since a
+/// thread can't be stopped from outside, but its result is ignored.
+pub fn run_days<F>(
+    solutions: &[&'static dyn DynSolution],
+    options: &PoolOptions,
+    job: F,
+    mut on_finish: impl FnMut(&Report),
+) -> Vec<Report>
+where
+    F: Fn(&'static dyn DynSolution) -> Report + Send + Sync + 'static,
+{
+    let job = Arc::new(job);
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut reports: Vec<Option<Report>> = solutions.iter().map(|_| None).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.max(1) {
+            let sender = sender.clone();
+            let (job, next) = (Arc::clone(&job), &next);
+            scope.spawn(move || {
+                while let Some(&solution) = solutions.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let report = run_one(solution, Arc::clone(&job), options.timeout);
+                    if sender.send(report).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        for report in receiver {
+            on_finish(&report);
+            let index = solutions
+                .iter()
+                .position(|s| (s.year(), s.day()) == (report.year, report.day))
+                .expect("reports are only made for the given solutions");
+            reports[index] = Some(report);
+        }
+    });
+
+    reports.into_iter().flatten().collect()
+}
+
+fn run_one<F>(solution: &'static dyn DynSolution, job: Arc<F>, timeout: Option<Duration>) -> Report
+where
+    F: Fn(&'static dyn DynSolution) -> Report + Send + Sync + 'static,
+{
+    let (year, day) = (solution.year(), solution.day());
+    let (sender, receiver) = mpsc::sync_channel(1);
+    let spawned = thread::Builder::new()
+        .name(format!("{year}-{day:02}"))
+        .spawn(move || {
+            IN_DAY.set(true);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| job(solution)))
+                .map_err(|payload| panic_message(payload.as_ref()));
+            let _ = sender.send(result);
+        });
+    if let Err(e) = spawned {
+        let message = format!("could not start a thread: {e}");
+        return Report::interrupted(year, day, Interruption::Panicked(message));
+    }
+
+    let result = match timeout {
+        Some(limit) => receiver.recv_timeout(limit),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+    let interruption = match result {
+        Ok(Ok(report)) => return report,
+        Ok(Err(message)) => Interruption::Panicked(message),
+        Err(RecvTimeoutError::Timeout) => Interruption::TimedOut(timeout.unwrap_or_default()),
+        Err(RecvTimeoutError::Disconnected) => {
+            Interruption::Panicked("the day stopped without a report".to_string())
+        }
+    };
+    Report::interrupted(year, day, interruption)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::run::{DayStatus, Outcome};
+    use aoc_common::error::Result;
+    use aoc_common::solution::{Params, Solution, Timings};
+
+    macro_rules! solution {
+        ($name:ident, $day:expr, $body:expr) => {
+            struct $name;
+
+            impl Solution for $name {
+                const YEAR: u16 = 2000;
+                const DAY: u8 = $day;
+                const SAMPLE: &'static str = "";
+                const INPUT: &'static str = "";
+
+                type Input<'a> = ();
+                type PartOne = u8;
+                type PartTwo = u8;
+
+                fn parse(_input: &str) -> Result<()> {
+                    Ok(())
+                }
+
+                fn part_one(_input: &(), _params: &Params) -> Result<u8> {
+                    $body
+                }
+
+                fn part_two(_input: &(), _params: &Params) -> Result<u8> {
+                    Ok(2)
+                }
+            }
+        };
+    }
+
+    solution!(Works, 1, Ok(1));
+    solution!(Panics, 2, panic!("index out of bounds"));
+    solution!(Sleeps, 3, {
+        thread::sleep(Duration::from_secs(5));
+        Ok(1)
+    });
+
+    fn job(solution: &'static dyn DynSolution) -> Report {
+        let run = solution.run("", &Params::new());
+        Report {
+            year: solution.year(),
+            day: solution.day(),
+            interruption: None,
+            origin: None,
+            expected: None,
+            part_one: run.part_one.into(),
+            part_two: run.part_two.into(),
+            timings: Timings::default(),
+        }
+    }
+
+    #[test]
+    fn test_capture_panics_passes_on_other_panics() {
+        static OUTSIDE: AtomicUsize = AtomicUsize::new(0);
+        panic::set_hook(Box::new(|_| {
+            if thread::current().name() == Some("outside") {
+                OUTSIDE.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+        capture_panics();
+
+        let outside = thread::Builder::new()
+            .name("outside".to_string())
+            .spawn(|| panic!("not in a day"))
+            .unwrap();
+        assert!(outside.join().is_err());
+        let report = run_one(&Panics, Arc::new(job), None);
+        let _ = panic::take_hook();
+
+        assert_eq!(OUTSIDE.load(Ordering::Relaxed), 1);
+        assert!(matches!(
+            &report.interruption,
+            Some(Interruption::Panicked(message)) if message.contains("pool.rs")
+        ));
+    }
+
+    #[test]
+    fn test_run_days() {
+        let solutions: &[&'static dyn DynSolution] = &[&Works, &Panics, &Sleeps];
+        let options = PoolOptions {
+            jobs: 2,
+            timeout: Some(Duration::from_millis(200)),
+        };
+
+        let mut finished = 0;
+        let reports = run_days(solutions, &options, job, |_| finished += 1);
+        assert_eq!(finished, 3);
+
+        let statuses: Vec<_> = reports.iter().map(|r| (r.day, r.status())).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, DayStatus::Passed),
+                (2, DayStatus::Panicked),
+                (3, DayStatus::TimedOut)
+            ]
+        );
+        assert!(matches!(
+            &reports[1].interruption,
+            Some(Interruption::Panicked(message)) if message.contains("index out of bounds")
+        ));
+        assert!(matches!(&reports[1].part_one, Outcome::Failed(_)));
+    }
+}
@@ -0,0 +1,190 @@
+//! Every solved day that the runner knows about, grouped by year.
+
+use std::error::Error;
+
+use aoc_common::answers::Answers;
+use aoc_common::solution::DynSolution;
+
+/// The solutions for one year's event and its expected answers.
+pub struct Year {
+    pub year: u16,
+    /// The year's `answers.toml`.
+    pub manifest: &'static str,
+    /// The solved days, sorted by day.
+    pub solutions: &'static [&'static dyn DynSolution],
+}
+
+impl Year {
+    pub fn answers(&self) -> Result<Answers, Box<dyn Error>> {
+        Answers::parse(self.manifest).map_err(|e| format!("{}/answers.toml: {e}", self.year).into())
+    }
+}
+
+/// Every year with solutions, sorted by year.
+pub static YEARS: &[Year] = &[Year {
+    year: 2025,
+    manifest: include_str!("../../2025/answers.toml"),
+    solutions: &[
+        &aoc_2025_01::Day01,
+        &aoc_2025_02::Day02,
+        &aoc_2025_03::Day03,
+        &aoc_2025_04::Day04,
+        &aoc_2025_05::Day05,
+        &aoc_2025_06::Day06,
+        &aoc_2025_07::Day07,
+        &aoc_2025_08::Day08,
+        &aoc_2025_09::Day09,
+    ],
+}];
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS
+        .binary_search_by_key(&year, |y| y.year)
+        .ok()
+        .map(|index| &YEARS[index])
+}
+
+/// Every registered solution, sorted by year and then day.
+pub fn solutions() -> impl Iterator<Item = &'static dyn DynSolution> {
+    YEARS.iter().flat_map(|y| y.solutions.iter().copied())
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn DynSolution> {
+    let solutions = self::year(year)?.solutions;
+    solutions
+        .binary_search_by_key(&day, |s| s.day())
+        .ok()
+        .map(|index| solutions[index])
+}
+
+/// The expected answers for a year, which are empty if the year has no
+/// solutions.
+pub fn answers(year: u16) -> Result<Answers, Box<dyn Error>> {
+    match self::year(year) {
+        Some(year) => year.answers(),
+        None => Ok(Answers::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fuzz;
+    use aoc_common::input::InputKind;
+    use aoc_common::property;
+    use aoc_common::random::Rng;
+    use aoc_common::solution::Part;
+    use aoc_common::year::check_day;
+
+    #[test]
+    fn test_solutions_are_sorted_and_unique() {
+        for pair in YEARS.windows(2) {
+            assert!(
+                pair[0].year < pair[1].year,
+                "{} is out of order",
+                pair[1].year
+            );
+        }
+        for year in YEARS {
+            for pair in year.solutions.windows(2) {
+                assert!(
+                    pair[0].day() < pair[1].day(),
+                    "{} day {} is registered out of order",
+                    year.year,
+                    pair[1].day()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_solutions_belong_to_their_year() {
+        for year in YEARS {
+            for solution in year.solutions {
+                assert_eq!(solution.year(), year.year);
+                check_day(solution.year(), solution.day()).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let solution = find(2025, 8).unwrap();
+        assert_eq!((solution.year(), solution.day()), (2025, 8));
+        assert!(find(2025, 0).is_none());
+        assert!(find(1999, 1).is_none());
+    }
+
+    #[test]
+    fn test_manifests_parse() {
+        for year in YEARS {
+            year.answers().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for year in YEARS {
+            let answers = year.answers().unwrap();
+            for solution in year.solutions {
+                let day = solution.day();
+                // The sample's params suit a small input.
+                let params = answers
+                    .day(day)
+                    .and_then(|a| a.get(InputKind::Sample))
+                    .map(|e| e.params.clone())
+                    .unwrap_or_default();
+                for seed in 0..3 {
+                    let Some(input) = solution.generate(&mut Rng::new(seed), 20) else {
+                        continue;
+                    };
+                    let run = solution.run(&input, &params);
+                    for part in Part::ALL {
+                        assert!(
+                            run.part(part).is_ok(),
+                            "{} Day {day:02} {part} failed on seed {seed}: {:?}",
+                            year.year,
+                            run.part(part)
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_solutions_agree_with_references() {
+        for year in YEARS {
+            let answers = year.answers().unwrap();
+            for solution in year.solutions {
+                let params = answers
+                    .day(solution.day())
+                    .and_then(|a| a.get(InputKind::Sample))
+                    .map(|e| e.params.clone())
+                    .unwrap_or_default();
+                if let Err(counterexample) = property::check(*solution, &params, 40, 12) {
+                    panic!("{} Day {:02} {counterexample}", year.year, solution.day());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fuzz_corpus_solves_without_panicking() {
+        for solution in solutions() {
+            let (year, day) = (solution.year(), solution.day());
+            let params = fuzz::params(self::year(year).unwrap().manifest, day).unwrap();
+            let corpus = fuzz::load(&fuzz::corpus_dir(year, day)).unwrap();
+            assert!(
+                !corpus.is_empty(),
+                "{year} Day {day:02} has no fuzz corpus in fuzz/corpus/{}",
+                fuzz::target(year, day)
+            );
+            for entry in corpus {
+                if let Err(message) = fuzz::check(solution, &entry.data, &params) {
+                    panic!("{year} Day {day:02} panicked on {}: {message}", entry.name);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,456 @@
+use std::io::{self, ErrorKind, Write};
+use std::time::Duration;
+
+use aoc_common::answers::{Answers, Check, Expected};
+use aoc_common::error::Result;
+use aoc_common::input::{InputKind, Inputs, Origin};
+use aoc_common::solution::{Answer, DynSolution, Part, Timings};
+use serde::Serialize;
+
+/// The outcome of solving a single part.
+pub enum Outcome {
+    Solved(Answer),
+    Failed(String),
+}
+
+impl Outcome {
+    fn as_str(&self) -> &str {
+        match self {
+            Outcome::Solved(answer) => &answer.value,
+            Outcome::Failed(_) => "-",
+        }
+    }
+}
+
+/// Why a day stopped before it could finish.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Interruption {
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+/// The overall result of a day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DayStatus {
+    Passed,
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl DayStatus {
+    fn label(self) -> &'static str {
+        match self {
+            DayStatus::Passed => "ok",
+            DayStatus::Failed => "FAILED",
+            DayStatus::Panicked => "PANICKED",
+            DayStatus::TimedOut => "TIMED OUT",
+        }
+    }
+}
+
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    /// Set when the day panicked or ran out of time, in which case neither
+    /// part has an answer.
+    pub interruption: Option<Interruption>,
+    /// Where the input came from, or `None` if it could not be loaded.
+    pub origin: Option<Origin>,
+    /// The manifest's answers for the input, which are only known for the
+    /// committed input.
+    pub expected: Option<Expected>,
+    pub part_one: Outcome,
+    pub part_two: Outcome,
+    pub timings: Timings,
+}
+
+impl Report {
+    /// The report for a day which never finished.
+    pub fn interrupted(year: u16, day: u8, interruption: Interruption) -> Self {
+        let reason = match &interruption {
+            Interruption::Panicked(message) => format!("panicked: {message}"),
+            Interruption::TimedOut(limit) => format!("did not finish within {limit:.2?}"),
+        };
+        Report {
+            year,
+            day,
+            interruption: Some(interruption),
+            origin: None,
+            expected: None,
+            part_one: Outcome::Failed(reason.clone()),
+            part_two: Outcome::Failed(reason),
+            timings: Timings::default(),
+        }
+    }
+
+    pub fn status(&self) -> DayStatus {
+        match self.interruption {
+            Some(Interruption::Panicked(_)) => DayStatus::Panicked,
+            Some(Interruption::TimedOut(_)) => DayStatus::TimedOut,
+            None if self.is_solved() => DayStatus::Passed,
+            None => DayStatus::Failed,
+        }
+    }
+
+    pub fn part(&self, part: Part) -> &Outcome {
+        match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        }
+    }
+
+    /// How the answer to `part` compares to the expected answer, if there is
+    /// an answer to compare.
+    pub fn check(&self, part: Part) -> Option<Check> {
+        match (self.part(part), &self.expected) {
+            (Outcome::Solved(answer), Some(expected)) => Some(expected.check(part, &answer.value)),
+            (Outcome::Solved(_), None) => Some(Check::Unknown),
+            (Outcome::Failed(_), _) => None,
+        }
+    }
+
+    /// Whether both parts have an answer and neither contradicts the manifest.
+    pub fn is_solved(&self) -> bool {
+        Part::ALL
+            .iter()
+            .all(|&part| matches!(self.check(part), Some(Check::Pass | Check::Unknown)))
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.timings.total()
+    }
+}
+
+impl From<Result<Answer>> for Outcome {
+    fn from(result: Result<Answer>) -> Self {
+        match result {
+            Ok(answer) => Outcome::Solved(answer),
+            Err(e) => Outcome::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Solves both parts of the day with the manifest's params for the real
+/// input, checking the answers against the manifest when the committed input
+/// is used.
+pub fn run_day(solution: &dyn DynSolution, inputs: &Inputs, answers: &Answers) -> Report {
+    let (year, day) = (solution.year(), solution.day());
+    let input = match inputs.load(year, day, solution.input()) {
+        Ok(input) => input,
+        Err(e) => {
+            return Report {
+                year,
+                day,
+                interruption: None,
+                origin: None,
+                expected: None,
+                part_one: Outcome::Failed(format!("could not read input: {e}")),
+                part_two: Outcome::Failed(format!("could not read input: {e}")),
+                timings: Timings::default(),
+            };
+        }
+    };
+
+    let actual = answers
+        .day(day)
+        .and_then(|a| a.get(InputKind::Actual))
+        .cloned()
+        .unwrap_or_default();
+    let run = solution.run(input.text.trim(), &actual.params);
+    Report {
+        year,
+        day,
+        interruption: None,
+        expected: (input.origin == Origin::Embedded).then_some(actual),
+        origin: Some(input.origin),
+        part_one: run.part_one.into(),
+        part_two: run.part_two.into(),
+        timings: run.timings,
+    }
+}
+
+/// Prints the result of a single day as soon as it has finished.
+pub fn print_report(out: &mut impl Write, report: &Report) -> io::Result<()> {
+    let status = report.status().label();
+    if let Some(interruption) = &report.interruption {
+        let reason = match interruption {
+            Interruption::Panicked(message) => message.clone(),
+            Interruption::TimedOut(limit) => format!("still running after {limit:.2?}"),
+        };
+        return writeln!(
+            out,
+            "{} Day {:02}: {status}: {reason}",
+            report.year, report.day
+        );
+    }
+    let origin = match &report.origin {
+        Some(origin @ (Origin::File(_) | Origin::Stdin)) => format!(" ({origin})"),
+        _ => String::new(),
+    };
+    let timings = &report.timings;
+    writeln!(
+        out,
+        "{} Day {:02}{origin}: {status} in {:.2?} (parsed in {:.2?})",
+        report.year,
+        report.day,
+        report.elapsed(),
+        timings.parse
+    )?;
+    for (part, time) in [(Part::One, timings.part_one), (Part::Two, timings.part_two)] {
+        match (report.part(part), report.check(part)) {
+            (Outcome::Solved(answer), Some(Check::Mismatch { expected })) => {
+                writeln!(
+                    out,
+                    "  {part}: {answer} in {time:.2?} (expected {expected})"
+                )?;
+            }
+            (Outcome::Solved(answer), _) => writeln!(out, "  {part}: {answer} in {time:.2?}")?,
+            (Outcome::Failed(error), _) => writeln!(out, "  {part} failed: {error}")?,
+        }
+    }
+    Ok(())
+}
+
+/// Prints a table with one row per day, followed by the overall totals and
+/// the `wall` clock time taken to run them all.
+pub fn print_summary(out: &mut impl Write, reports: &[Report], wall: Duration) -> io::Result<()> {
+    let rows: Vec<[String; 6]> = reports
+        .iter()
+        .map(|r| {
+            [
+                r.year.to_string(),
+                format!("{:02}", r.day),
+                r.part_one.as_str().to_string(),
+                r.part_two.as_str().to_string(),
+                r.status().label().to_string(),
+                if r.interruption.is_some() {
+                    "-".to_string()
+                } else {
+                    format!("{:.2?}", r.elapsed())
+                },
+            ]
+        })
+        .collect();
+    let header = ["Year", "Day", "Part One", "Part Two", "Status", "Time"].map(String::from);
+
+    let mut widths = header.clone().map(|h| h.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        writeln!(out, "{}", line.join("  ").trim_end())?;
+    }
+
+    let count = |status| reports.iter().filter(|r| r.status() == status).count();
+    let total: Duration = reports.iter().map(Report::elapsed).sum();
+    writeln!(
+        out,
+        "\n{} passed, {} failed, {} panicked, {} timed out",
+        count(DayStatus::Passed),
+        count(DayStatus::Failed),
+        count(DayStatus::Panicked),
+        count(DayStatus::TimedOut),
+    )?;
+    writeln!(out, "{total:.2?} spent solving, {wall:.2?} in total")
+}
+
+/// Treats stdout being closed, as when the output is piped into `head`, as
+/// the reader having seen enough rather than as an error.
+pub fn ignore_broken_pipe(result: io::Result<()>) -> io::Result<()> {
+    match result {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+/// How a part's answer compares to the manifest, for machine readable output.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There is an answer but nothing to compare it against.
+    Unknown,
+    /// The part couldn't be solved.
+    Error,
+}
+
+/// One part of one day, as written by `--format json`.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct PartRecord<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a str>,
+    pub answer_type: Option<&'static str>,
+    /// Time taken to solve the part, not including parsing.
+    pub duration_ns: u64,
+    pub status: Status,
+    pub expected: Option<&'a str>,
+    pub error: Option<&'a str>,
+}
+
+/// The records for both parts of a day.
+pub fn part_records(report: &Report) -> Vec<PartRecord<'_>> {
+    Part::ALL
+        .iter()
+        .map(|&part| {
+            let duration = match part {
+                Part::One => report.timings.part_one,
+                Part::Two => report.timings.part_two,
+            };
+            let (answer, error) = match report.part(part) {
+                Outcome::Solved(answer) => (Some(answer), None),
+                Outcome::Failed(error) => (None, Some(error.as_str())),
+            };
+            let status = match report.check(part) {
+                Some(Check::Pass) => Status::Pass,
+                Some(Check::Mismatch { .. }) => Status::Fail,
+                Some(Check::Unknown) => Status::Unknown,
+                None => Status::Error,
+            };
+            PartRecord {
+                year: report.year,
+                day: report.day,
+                part: part.number(),
+                answer: answer.map(|a| a.value.as_str()),
+                answer_type: answer.map(|a| a.kind),
+                duration_ns: u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX),
+                status,
+                expected: report.expected.as_ref().and_then(|e| e.answer(part)),
+                error,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(part_one: Outcome, expected: Option<Expected>) -> Report {
+        Report {
+            year: 2025,
+            day: 3,
+            interruption: None,
+            origin: Some(Origin::Embedded),
+            expected,
+            part_one,
+            part_two: Outcome::Failed("line 1, column 1: expected a digit".to_string()),
+            timings: Timings {
+                parse: Duration::from_nanos(5),
+                part_one: Duration::from_nanos(10),
+                part_two: Duration::from_nanos(20),
+            },
+        }
+    }
+
+    fn solved(value: &str) -> Outcome {
+        Outcome::Solved(Answer {
+            value: value.to_string(),
+            kind: "u64",
+        })
+    }
+
+    #[test]
+    fn test_part_records() {
+        let expected = Expected {
+            part_one: Some("357".to_string()),
+            ..Expected::default()
+        };
+        let report = report(solved("357"), Some(expected));
+        let records = part_records(&report);
+        assert_eq!(
+            records[0],
+            PartRecord {
+                year: 2025,
+                day: 3,
+                part: 1,
+                answer: Some("357"),
+                answer_type: Some("u64"),
+                duration_ns: 10,
+                status: Status::Pass,
+                expected: Some("357"),
+                error: None,
+            }
+        );
+        assert_eq!(records[1].status, Status::Error);
+        assert_eq!(records[1].error, Some("line 1, column 1: expected a digit"));
+        assert_eq!(report.status(), DayStatus::Failed);
+    }
+
+    #[test]
+    fn test_part_records_status() {
+        let expected = Expected {
+            part_one: Some("357".to_string()),
+            ..Expected::default()
+        };
+        let records = |report| part_records(&report)[0].status;
+        assert_eq!(records(report(solved("358"), Some(expected))), Status::Fail);
+        assert_eq!(records(report(solved("358"), None)), Status::Unknown);
+    }
+
+    /// Accepts `limit` bytes, then fails as if the reader had gone away.
+    struct Pipe {
+        written: Vec<u8>,
+        limit: usize,
+    }
+
+    impl Write for Pipe {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.written.len() + buf.len() > self.limit {
+                return Err(ErrorKind::BrokenPipe.into());
+            }
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_print_report() {
+        let report = report(solved("357"), None);
+        let mut out = Vec::new();
+        print_report(&mut out, &report).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "2025 Day 03: FAILED in 35.00ns (parsed in 5.00ns)\n  \
+             Part One: 357 in 10.00ns\n  \
+             Part Two failed: line 1, column 1: expected a digit\n"
+        );
+
+        let mut pipe = Pipe {
+            written: Vec::new(),
+            limit: 60,
+        };
+        let result = print_summary(&mut pipe, &[report], Duration::from_secs(1));
+        assert_eq!(
+            result.as_ref().map_err(io::Error::kind),
+            Err(ErrorKind::BrokenPipe)
+        );
+        assert!(ignore_broken_pipe(result).is_ok());
+        assert!(ignore_broken_pipe(Err(ErrorKind::Other.into())).is_err());
+    }
+
+    #[test]
+    fn test_part_records_json() {
+        let report = report(solved("357"), None);
+        let json = serde_json::to_string(&part_records(&report)[0]).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":2025,"day":3,"part":1,"answer":"357","answer_type":"u64","duration_ns":10,"status":"unknown","expected":null,"error":null}"#
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Generates the crate for a new day from the templates in `runner/templates/day`.
+
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_common::year::check_day;
+
+const CARGO_TOML: &str = include_str!("../templates/day/Cargo.toml");
+const MAIN_RS: &str = include_str!("../templates/day/main.rs");
+const LIB_RS: &str = include_str!("../templates/day/lib.rs");
+const ANSWERS_TOML: &str = include_str!("../templates/day/answers.toml");
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &format!("{day:02}"))
+        .replace("{{day_number}}", &day.to_string())
+}
+
+/// Whether a workspace member is a day crate, e.g. `2025/01`.
+fn is_day(member: &str) -> bool {
+    member.split_once('/').is_some_and(|(year, day)| {
+        year.len() == 4
+            && day.len() == 2
+            && year.chars().chain(day.chars()).all(|c| c.is_ascii_digit())
+    })
+}
+
+/// Adds `member` to the `members` list of the workspace `manifest`, keeping
+/// the days sorted and the rest of the file untouched.
+pub fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "members = [")
+        .ok_or("could not find the workspace `members` list")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("the workspace `members` list is not closed")?;
+
+    let members: Vec<&str> = lines[start + 1..end]
+        .iter()
+        .map(|line| line.trim().trim_end_matches(',').trim_matches('"'))
+        .collect();
+    if members.contains(&member) {
+        return Err(format!("{member} is already a workspace member"));
+    }
+    let index = members
+        .iter()
+        .position(|&m| is_day(m) && m > member)
+        .unwrap_or(members.len());
+    let indent = lines[start + 1..end]
+        .first()
+        .map_or("    ", |line| &line[..line.len() - line.trim_start().len()]);
+
+    let new_line = format!("{indent}\"{member}\",");
+    let mut updated: Vec<&str> = lines.clone();
+    updated.insert(start + 1 + index, &new_line);
+    let mut text = updated.join("\n");
+    if manifest.ends_with('\n') {
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+/// Creates the crate for a day under `root`, the workspace directory, and
+/// adds it to the workspace. Returns the new crate's directory.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, Box<dyn Error>> {
+    check_day(year, day)?;
+    let year_dir = root.join(year.to_string());
+    let dir = year_dir.join(format!("{day:02}"));
+    if dir.exists() {
+        return Err(format!("{year}/{day:02} already exists").into());
+    }
+
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = fs::read_to_string(&workspace_path)
+        .map_err(|e| format!("could not read {}: {e}", workspace_path.display()))?;
+    let workspace = add_member(&workspace, &format!("{year}/{day:02}"))?;
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src)?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML, year, day))?;
+    fs::write(src.join("main.rs"), fill(MAIN_RS, year, day))?;
+    fs::write(src.join("lib.rs"), fill(LIB_RS, year, day))?;
+    fs::write(src.join("data-sample.txt"), "")?;
+    fs::write(src.join("data-actual.txt"), "")?;
+
+    let answers = year_dir.join("answers.toml");
+    if !answers.exists() {
+        fs::write(answers, fill(ANSWERS_TOML, year, day))?;
+    }
+    fs::write(workspace_path, workspace)?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::temp::TempDir;
+
+    const WORKSPACE: &str = r#"[workspace]
+resolver = "3"
+members = [
+    "common",
+    "runner",
+    "2024/25",
+    "2025/01",
+    "2025/03",
+]
+
+[workspace.dependencies]
+aoc-common = { path = "common" }
+"#;
+
+    #[test]
+    fn test_add_member_keeps_days_sorted() {
+        let updated = add_member(WORKSPACE, "2025/02").unwrap();
+        assert!(updated.contains("    \"2025/01\",\n    \"2025/02\",\n    \"2025/03\",\n"));
+        assert!(updated.ends_with("aoc-common = { path = \"common\" }\n"));
+
+        let updated = add_member(WORKSPACE, "2024/01").unwrap();
+        assert!(updated.contains("    \"runner\",\n    \"2024/01\",\n    \"2024/25\",\n"));
+
+        let updated = add_member(WORKSPACE, "2025/04").unwrap();
+        assert!(updated.contains("    \"2025/03\",\n    \"2025/04\",\n]"));
+    }
+
+    #[test]
+    fn test_add_member_errors() {
+        assert_eq!(
+            add_member(WORKSPACE, "2025/01"),
+            Err("2025/01 is already a workspace member".to_string())
+        );
+        assert!(add_member("[workspace]\n", "2025/01").is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = TempDir::new("scaffold");
+        fs::write(root.join("Cargo.toml"), WORKSPACE).unwrap();
+
+        let dir = new_day(&root, 2025, 2).unwrap();
+        assert_eq!(dir, root.join("2025/02"));
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day02;"));
+        assert!(lib.contains("const DAY: u8 = 2;"));
+        let main = fs::read_to_string(dir.join("src/main.rs")).unwrap();
+        assert!(main.contains("use aoc_2025_02::Day02;"));
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc-2025-02\""));
+        assert!(dir.join("src/data-sample.txt").exists());
+        assert!(root.join("2025/answers.toml").exists());
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("\"2025/02\","));
+
+        fs::write(dir.join("src/data-actual.txt"), "mine").unwrap();
+        let error = new_day(&root, 2025, 2).unwrap_err();
+        assert_eq!(error.to_string(), "2025/02 already exists");
+        let actual = fs::read_to_string(dir.join("src/data-actual.txt")).unwrap();
+        assert_eq!(actual, "mine");
+
+        assert!(new_day(&root, 2025, 13).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::solution::Part;
+use aoc_common::year::{check_day, puzzle_count};
+
+/// Which registered days a command should apply to.
+#[derive(Debug, Default, PartialEq)]
+pub struct Selection {
+    /// `None` selects every year.
+    pub year: Option<u16>,
+    /// `None` selects every day of the selected year(s).
+    pub days: Option<RangeInclusive<u8>>,
+}
+
+impl Selection {
+    pub fn matches(&self, year: u16, day: u8) -> bool {
+        self.year.is_none_or(|y| y == year) && self.days.as_ref().is_none_or(|d| d.contains(&day))
+    }
+
+    /// Checks that the selected year had an event and that it had the
+    /// selected days.
+    pub fn check(&self) -> Result<(), String> {
+        let Some(year) = self.year else {
+            return Ok(());
+        };
+        match &self.days {
+            Some(days) => {
+                check_day(year, *days.start()).and_then(|()| check_day(year, *days.end()))
+            }
+            None => puzzle_count(year)
+                .map(|_| ())
+                .ok_or_else(|| format!("there was no Advent of Code in {year}")),
+        }
+    }
+}
+
+/// Parses a single day (`7`) or a range of days, which is inclusive when
+/// written `3-5` or `3..=5` and, as in Rust, leaves out the end when written
+/// `3..6`.
+pub fn parse_days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse = |n: &str| {
+        n.trim()
+            .parse::<u8>()
+            .map_err(|e| format!("invalid day `{n}`: {e}"))
+    };
+
+    let range = if let Some((start, end)) = s.split_once("..=") {
+        parse(start)?..=parse(end)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let (start, end) = (parse(start)?, parse(end)?);
+        match end.checked_sub(1) {
+            Some(last) => start..=last,
+            None => return Err(format!("day range `{s}` is empty")),
+        }
+    } else if let Some((start, end)) = s.split_once('-') {
+        parse(start)?..=parse(end)?
+    } else {
+        let day = parse(s)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("day range `{s}` is empty"));
+    }
+
+    Ok(range)
+}
+
+/// Parses a part number, `1` or `2`.
+pub fn parse_part(s: &str) -> Result<Part, String> {
+    match s.trim() {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part `{s}`, expected 1 or 2")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(7..=7));
+        assert_eq!(parse_days("3-5"), Ok(3..=5));
+        assert_eq!(parse_days("3..6"), Ok(3..=5));
+        assert_eq!(parse_days("3..=5"), Ok(3..=5));
+        assert_eq!(parse_days("3..4"), Ok(3..=3));
+        assert_eq!(
+            parse_days("3..3"),
+            Err("day range `3..3` is empty".to_string())
+        );
+        assert!(parse_days("0..0").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("1"), Ok(Part::One));
+        assert_eq!(parse_part("2"), Ok(Part::Two));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_selection_check() {
+        let selection = |year, days| Selection { year, days };
+        assert_eq!(selection(None, None).check(), Ok(()));
+        assert_eq!(selection(Some(2024), Some(20..=25)).check(), Ok(()));
+        assert_eq!(
+            selection(Some(2025), Some(10..=13)).check(),
+            Err("2025 has days 1 to 12, not 13".to_string())
+        );
+        assert!(selection(Some(2014), None).check().is_err());
+    }
+
+    #[test]
+    fn test_selection_matches() {
+        assert!(Selection::default().matches(2025, 1));
+
+        let year = Selection {
+            year: Some(2025),
+            days: None,
+        };
+        assert!(year.matches(2025, 9));
+        assert!(!year.matches(2024, 9));
+
+        let days = Selection {
+            year: Some(2025),
+            days: Some(3..=5),
+        };
+        assert!(!days.matches(2025, 2));
+        assert!(days.matches(2025, 3));
+        assert!(days.matches(2025, 5));
+        assert!(!days.matches(2025, 6));
+    }
+}
@@ -0,0 +1,368 @@
+//! Submits answers, remembering every guess so that an answer already known
+//! to be wrong is never sent again.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc_common::solution::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// Where guesses are recorded, relative to the workspace root.
+pub const GUESSES_FILE: &str = "guesses.toml";
+
+/// What the site made of a submitted answer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Reply {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, and another can be submitted
+    /// after the wait.
+    RateLimited(Duration),
+    /// The part has already been solved, or isn't unlocked yet.
+    WrongLevel,
+    /// The reply didn't match any known message, so it is kept as it is.
+    Unrecognised(String),
+}
+
+impl Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Correct => write!(f, "correct"),
+            Reply::Wrong => write!(f, "wrong"),
+            Reply::TooHigh => write!(f, "wrong, too high"),
+            Reply::TooLow => write!(f, "wrong, too low"),
+            Reply::RateLimited(wait) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Reply::WrongLevel => write!(f, "already solved, or not unlocked yet"),
+            Reply::Unrecognised(message) => write!(f, "unrecognised reply: {message}"),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, without its markup.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads a wait such as `You have 4m 37s left to wait`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for part in wait.split_whitespace() {
+        let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+pub fn parse_reply(page: &str) -> Reply {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Reply::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Reply::TooHigh
+        } else if text.contains("too low") {
+            Reply::TooLow
+        } else {
+            Reply::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Reply::RateLimited(parse_wait(&text).unwrap_or_default())
+    } else if text.contains("You don't seem to be solving the right level") {
+        Reply::WrongLevel
+    } else {
+        Reply::Unrecognised(text)
+    }
+}
+
+/// What was learnt from a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    fn from_reply(reply: &Reply) -> Option<Self> {
+        match reply {
+            Reply::Correct => Some(Outcome::Correct),
+            Reply::Wrong => Some(Outcome::Wrong),
+            Reply::TooHigh => Some(Outcome::TooHigh),
+            Reply::TooLow => Some(Outcome::TooLow),
+            Reply::RateLimited(_) | Reply::WrongLevel | Reply::Unrecognised(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Guess {
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayGuesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part_one: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part_two: Vec<Guess>,
+}
+
+impl DayGuesses {
+    fn part_mut(&mut self, part: Part) -> &mut Vec<Guess> {
+        match part {
+            Part::One => &mut self.part_one,
+            Part::Two => &mut self.part_two,
+        }
+    }
+}
+
+/// Every answer submitted so far, keyed by year and then by two-digit day,
+/// e.g. `[[2025.07.part_one]]`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Guesses(BTreeMap<String, BTreeMap<String, DayGuesses>>);
+
+impl Guesses {
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Reads the guesses at `path`, or none if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {e}", path.display()).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display()).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)
+            .map_err(|e| format!("could not write {}: {e}", path.display()).into())
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> &[Guess] {
+        let Some(day) = self
+            .0
+            .get(&year.to_string())
+            .and_then(|days| days.get(&format!("{day:02}")))
+        else {
+            return &[];
+        };
+        match part {
+            Part::One => &day.part_one,
+            Part::Two => &day.part_two,
+        }
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, guess: Guess) {
+        self.0
+            .entry(year.to_string())
+            .or_default()
+            .entry(format!("{day:02}"))
+            .or_default()
+            .part_mut(part)
+            .push(guess);
+    }
+
+    /// Explains why `answer` would be a wasted submission, if the earlier
+    /// guesses already show that it's wrong or the part is solved.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<(), String> {
+        let number = answer.parse::<i128>().ok();
+        for guess in self.get(year, day, part) {
+            let earlier = guess.answer.parse::<i128>().ok();
+            match guess.outcome {
+                Outcome::Correct => {
+                    return Err(format!("{part} was already solved with {}", guess.answer));
+                }
+                _ if guess.answer == answer => {
+                    return Err(format!("{answer} was already submitted and was wrong"));
+                }
+                Outcome::TooHigh if number.zip(earlier).is_some_and(|(n, e)| n >= e) => {
+                    return Err(format!("{answer} is too high, since {} was", guess.answer));
+                }
+                Outcome::TooLow if number.zip(earlier).is_some_and(|(n, e)| n <= e) => {
+                    return Err(format!("{answer} is too low, since {} was", guess.answer));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Submits `answer` unless the guesses recorded at `guesses_path` show it
+/// would be wrong, then records what the site made of it.
+pub fn submit_answer(
+    client: &Client,
+    guesses_path: &Path,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Reply, Box<dyn Error>> {
+    let mut guesses = Guesses::load(guesses_path)?;
+    guesses.check(year, day, part, answer)?;
+
+    let reply = parse_reply(&client.answer(year, day, part, answer)?);
+    if let Some(outcome) = Outcome::from_reply(&reply) {
+        let answer = answer.to_string();
+        guesses.record(year, day, part, Guess { answer, outcome });
+        guesses.save(guesses_path)?;
+    }
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+    use aoc_common::temp::TempDir;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(
+            parse_reply(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            Reply::Correct
+        );
+        assert_eq!(
+            parse_reply(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, ..."
+            )),
+            Reply::TooHigh
+        );
+        assert_eq!(
+            parse_reply(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Reply::TooLow
+        );
+        assert_eq!(
+            parse_reply(&page("That's not the right answer.  If you're stuck, ...")),
+            Reply::Wrong
+        );
+        assert_eq!(
+            parse_reply(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 4m 37s left to wait."
+            )),
+            Reply::RateLimited(Duration::from_secs(277))
+        );
+        assert_eq!(
+            parse_reply(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Reply::WrongLevel
+        );
+        assert_eq!(
+            parse_reply(&page("Something <em>new</em>.")),
+            Reply::Unrecognised("Something new.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_guesses_check() {
+        let mut guesses = Guesses::default();
+        let guess = |answer: &str, outcome| Guess {
+            answer: answer.to_string(),
+            outcome,
+        };
+        guesses.record(2025, 7, Part::One, guess("100", Outcome::TooHigh));
+        guesses.record(2025, 7, Part::One, guess("10", Outcome::TooLow));
+        guesses.record(2025, 7, Part::One, guess("50", Outcome::Wrong));
+
+        assert_eq!(guesses.check(2025, 7, Part::One, "42"), Ok(()));
+        assert!(guesses.check(2025, 7, Part::One, "50").is_err());
+        assert!(guesses.check(2025, 7, Part::One, "100").is_err());
+        assert!(guesses.check(2025, 7, Part::One, "150").is_err());
+        assert!(guesses.check(2025, 7, Part::One, "9").is_err());
+        assert_eq!(guesses.check(2025, 7, Part::Two, "50"), Ok(()));
+
+        guesses.record(2025, 7, Part::Two, guess("8", Outcome::Correct));
+        assert_eq!(
+            guesses.check(2025, 7, Part::Two, "9"),
+            Err("Part Two was already solved with 8".to_string())
+        );
+
+        let text = toml::to_string(&guesses).unwrap();
+        assert!(text.contains("[[2025.07.part_one]]"), "{text}");
+        assert_eq!(Guesses::parse(&text).unwrap(), guesses);
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let server = TestServer::start(|request| {
+            let reply = if request.body.contains("answer=42") {
+                "That's the right answer!"
+            } else {
+                "That's not the right answer; your answer is too low."
+            };
+            (200, page(reply))
+        });
+        let client = Client::new(&server.url, Some("secret".to_string()));
+        let dir = TempDir::new("submit");
+        let path = dir.join(GUESSES_FILE);
+
+        let reply = submit_answer(&client, &path, 2025, 7, Part::Two, "41").unwrap();
+        assert_eq!(reply, Reply::TooLow);
+        assert!(submit_answer(&client, &path, 2025, 7, Part::Two, "41").is_err());
+        assert!(submit_answer(&client, &path, 2025, 7, Part::Two, "40").is_err());
+        let reply = submit_answer(&client, &path, 2025, 7, Part::Two, "42").unwrap();
+        assert_eq!(reply, Reply::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2025/day/7/answer");
+        assert_eq!(requests[0].body, "level=2&answer=41");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+
+        let guesses = Guesses::load(&path).unwrap();
+        assert_eq!(guesses.get(2025, 7, Part::Two).len(), 2);
+    }
+}
@@ -0,0 +1,198 @@
+use std::borrow::Cow;
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc_common::answers::{Answers, Check, Expected};
+use aoc_common::encryption::reveal;
+use aoc_common::input::InputKind;
+use aoc_common::samples::Sample;
+use aoc_common::solution::{DynSolution, Part};
+
+use crate::pool::panic_message;
+
+/// The result of checking one part of one input against the manifest.
+pub enum Verdict {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    Failed(String),
+    /// The manifest has no answer for this part.
+    Missing,
+}
+
+pub struct Verification {
+    /// The input that was solved: `sample`, `actual`, or the name of a file
+    /// in the day's `samples` directory.
+    pub input: String,
+    pub part: Part,
+    pub verdict: Verdict,
+}
+
+/// Solves one part and compares it with the expected answer. A panic fails
+/// the part rather than the whole verification.
+fn check_part(solution: &dyn DynSolution, part: Part, input: &str, expected: &Expected) -> Verdict {
+    if expected.answer(part).is_none() {
+        return Verdict::Missing;
+    }
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        solution.solve(part, input, &expected.params)
+    }));
+    let solved = match solved {
+        Ok(solved) => solved,
+        Err(payload) => {
+            return Verdict::Failed(format!("panicked: {}", panic_message(payload.as_ref())));
+        }
+    };
+    match solved {
+        Ok(answer) => match expected.check(part, &answer.value) {
+            Check::Pass => Verdict::Pass,
+            Check::Mismatch { expected } => Verdict::Mismatch {
+                expected,
+                actual: answer.value,
+            },
+            Check::Unknown => Verdict::Missing,
+        },
+        Err(e) => Verdict::Failed(e.to_string()),
+    }
+}
+
+/// Solves every part of the day's committed inputs and compares the answers
+/// with the manifest, then solves the `samples` for the parts they apply to.
+pub fn verify_day(
+    solution: &dyn DynSolution,
+    answers: &Answers,
+    samples: &[Sample],
+) -> Vec<Verification> {
+    let expected = answers.day(solution.day());
+    let mut verifications = Vec::new();
+    for kind in InputKind::ALL {
+        let verdicts = match expected.and_then(|e| e.get(kind)) {
+            None => Part::ALL.map(|part| (part, Verdict::Missing)),
+            Some(expected) => match reveal(Cow::Borrowed(solution.committed(kind))) {
+                Ok(input) => {
+                    Part::ALL.map(|part| (part, check_part(solution, part, input.trim(), expected)))
+                }
+                Err(e) => Part::ALL.map(|part| (part, Verdict::Failed(e.to_string()))),
+            },
+        };
+        verifications.extend(verdicts.map(|(part, verdict)| Verification {
+            input: kind.to_string(),
+            part,
+            verdict,
+        }));
+    }
+
+    for sample in samples {
+        for part in Part::ALL {
+            if sample.expected.answer(part).is_some() {
+                verifications.push(Verification {
+                    input: format!("samples/{}", sample.name),
+                    part,
+                    verdict: check_part(solution, part, sample.text.trim(), &sample.expected),
+                });
+            }
+        }
+    }
+    verifications
+}
+
+/// Prints one line per day, with details of anything which didn't pass.
+pub fn print_verification(year: u16, day: u8, verifications: &[Verification]) {
+    let passed = verifications
+        .iter()
+        .filter(|v| matches!(v.verdict, Verdict::Pass))
+        .count();
+    println!(
+        "{year} Day {day:02}: {passed}/{} checks passed",
+        verifications.len()
+    );
+    for Verification {
+        input,
+        part,
+        verdict,
+    } in verifications
+    {
+        match verdict {
+            Verdict::Pass => {}
+            Verdict::Mismatch { expected, actual } => {
+                println!("  {input} {part}: expected {expected}, got {actual}");
+            }
+            Verdict::Failed(error) => println!("  {input} {part} failed: {error}"),
+            Verdict::Missing => println!("  {input} {part}: no expected answer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::fixtures::Indexes;
+    use aoc_common::solution::Params;
+
+    #[test]
+    fn test_check_part_catches_panics() {
+        let expected = Expected {
+            params: Params::new(),
+            part_one: Some("2".to_string()),
+            part_two: Some("7".to_string()),
+        };
+        assert!(matches!(
+            check_part(&Indexes, Part::One, "5\n1", &expected),
+            Verdict::Pass
+        ));
+        assert!(matches!(
+            check_part(&Indexes, Part::Two, "5", &expected),
+            Verdict::Failed(message) if message.contains("index out of bounds")
+        ));
+        assert!(matches!(
+            check_part(&Indexes, Part::Two, "1\n7", &expected),
+            Verdict::Pass
+        ));
+    }
+}
+
+/// A test for every answer in the workspace, generated by `build.rs`.
+#[cfg(test)]
+mod generated {
+    use super::*;
+    use crate::registry;
+    use aoc_common::samples;
+
+    fn solution(year: u16, day: u8) -> &'static dyn DynSolution {
+        registry::find(year, day)
+            .unwrap_or_else(|| panic!("{year} Day {day:02} has answers but no solution"))
+    }
+
+    fn assert_passes(verdict: Verdict) {
+        match verdict {
+            Verdict::Pass => {}
+            Verdict::Mismatch { expected, actual } => {
+                panic!("expected {expected}, got {actual}")
+            }
+            Verdict::Failed(error) => panic!("{error}"),
+            Verdict::Missing => panic!("no expected answer"),
+        }
+    }
+
+    fn check_committed(year: u16, day: u8, kind: InputKind, part: Part) {
+        let solution = solution(year, day);
+        let answers = registry::answers(year).unwrap();
+        let expected = answers.day(day).and_then(|a| a.get(kind)).unwrap();
+        let input = reveal(Cow::Borrowed(solution.committed(kind))).unwrap();
+        assert_passes(check_part(solution, part, input.trim(), expected));
+    }
+
+    fn check_sample(year: u16, day: u8, name: &str, part: Part) {
+        let samples = samples::load(&samples::dir(year, day)).unwrap();
+        let sample = samples.iter().find(|s| s.name == name).unwrap();
+        assert_passes(check_part(
+            solution(year, day),
+            part,
+            sample.text.trim(),
+            &sample.expected,
+        ));
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/generated_tests.rs"));
+}
@@ -0,0 +1,241 @@
+//! Rebuilds and re-runs a day whenever its source or data files change.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+/// How long to wait for a burst of changes, such as an editor saving through
+/// a temporary file, to settle before rebuilding.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// The files to watch for a day under `root`, the workspace directory: the
+/// whole of the day's crate, so that a `samples` directory created while
+/// watching is picked up too, and the year's manifest of answers.
+pub fn watch_paths(root: &Path, year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        root.join(format!("{year}/{day:02}")),
+        root.join(format!("{year}/answers.toml")),
+    ]
+}
+
+/// Whether a changed file should trigger a rebuild, leaving out the backup
+/// and swap files that editors leave around.
+pub fn is_relevant(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    !(name.starts_with('.')
+        || name.starts_with('#')
+        || name.ends_with('~')
+        || name.ends_with(".swp")
+        || name.ends_with(".swx"))
+}
+
+/// The parts of a record written by `aoc run --format json` which are shown
+/// between runs.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ns: u64,
+    pub status: String,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Formats an answer, with how its time compares to the last run.
+#[allow(clippy::cast_precision_loss)]
+pub fn describe(answer: &Answer, previous: Option<&Answer>) -> String {
+    let time = format!("{:.2?}", Duration::from_nanos(answer.duration_ns));
+    let value = match (&answer.answer, &answer.error) {
+        (Some(value), _) => value.as_str(),
+        (None, Some(error)) => return format!("Part {}: {error}", answer.part),
+        (None, None) => "no answer",
+    };
+    let mut line = format!("Part {}: {value} in {time}", answer.part);
+    if let Some(before) = previous.map(|p| p.duration_ns).filter(|&ns| ns > 0) {
+        let percent = (answer.duration_ns as f64 - before as f64) / before as f64 * 100.0;
+        line.push_str(&format!(" ({percent:+.1}%)"));
+    }
+    if let Some(before) = previous.and_then(|p| p.answer.as_ref())
+        && Some(before) != answer.answer.as_ref()
+    {
+        line.push_str(&format!(", was {before}"));
+    }
+    if answer.status == "fail"
+        && let Some(expected) = &answer.expected
+    {
+        line.push_str(&format!(", expected {expected}"));
+    }
+    line
+}
+
+/// Runs `aoc` with `args` through cargo, rebuilding it first.
+fn cargo_aoc(root: &Path, release: bool, args: &[String]) -> Command {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()));
+    command
+        .current_dir(root)
+        .args(["run", "-q", "--bin", "aoc"]);
+    if release {
+        command.arg("--release");
+    }
+    command.arg("--").args(args);
+    command
+}
+
+/// Rebuilds the runner, checks the day's samples and runs it, printing the
+/// answers against those of the `previous` run. Returns the new answers, or
+/// `None` if the build or run failed.
+fn run_once(
+    root: &Path,
+    year: u16,
+    day: u8,
+    release: bool,
+    previous: &HashMap<u8, Answer>,
+) -> Option<HashMap<u8, Answer>> {
+    let day_args = [year.to_string(), day.to_string()];
+    let verify = [vec!["verify".to_string()], day_args.to_vec()].concat();
+    match cargo_aoc(root, release, &verify).status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => println!("Some checks failed"),
+        Err(e) => {
+            println!("Could not run cargo: {e}");
+            return None;
+        }
+    }
+
+    let run = [
+        vec!["run".to_string()],
+        day_args.to_vec(),
+        vec!["--format".to_string(), "json".to_string()],
+    ]
+    .concat();
+    let output = cargo_aoc(root, release, &run)
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+    let answers: Vec<Answer> = match serde_json::from_slice(&output.stdout) {
+        Ok(answers) => answers,
+        Err(_) => {
+            println!("{}", String::from_utf8_lossy(&output.stdout));
+            return None;
+        }
+    };
+    println!();
+    for answer in &answers {
+        println!("{}", describe(answer, previous.get(&answer.part)));
+    }
+    Some(answers.into_iter().map(|a| (a.part, a)).collect())
+}
+
+/// Watches a day until interrupted, re-running it after every change.
+pub fn watch(root: &Path, year: u16, day: u8, release: bool) -> Result<(), Box<dyn Error>> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    for path in watch_paths(root, year, day) {
+        watcher
+            .watch(&path, RecursiveMode::Recursive)
+            .map_err(|e| format!("could not watch {}: {e}", path.display()))?;
+    }
+
+    let mut previous = HashMap::new();
+    loop {
+        if let Some(answers) = run_once(root, year, day, release, &previous) {
+            previous = answers;
+        }
+        println!("\nWatching {year} Day {day:02} for changes...");
+
+        // Block until something relevant changes, then drain the rest of
+        // the burst.
+        loop {
+            let event = receiver.recv()??;
+            let modified = matches!(
+                event.kind,
+                EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+            );
+            if modified && event.paths.iter().any(|p| is_relevant(p)) {
+                break;
+            }
+        }
+        while receiver.recv_timeout(SETTLE).is_ok() {}
+        println!("\n--- {year} Day {day:02} changed, rebuilding ---\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(part: u8, value: &str, duration_ns: u64) -> Answer {
+        Answer {
+            part,
+            answer: Some(value.to_string()),
+            duration_ns,
+            status: "pass".to_string(),
+            expected: Some(value.to_string()),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_watch_paths() {
+        let root = Path::new("/aoc");
+        assert_eq!(
+            watch_paths(root, 2025, 7),
+            [root.join("2025/07"), root.join("2025/answers.toml")]
+        );
+    }
+
+    #[test]
+    fn test_is_relevant() {
+        assert!(is_relevant(Path::new("2025/07/src/lib.rs")));
+        assert!(is_relevant(Path::new("2025/07/src/data-actual.txt")));
+        assert!(!is_relevant(Path::new("2025/07/src/.lib.rs.swp")));
+        assert!(!is_relevant(Path::new("2025/07/src/lib.rs~")));
+        assert!(!is_relevant(Path::new("2025/07/src/#lib.rs#")));
+    }
+
+    #[test]
+    fn test_describe() {
+        let first = answer(1, "21", 2_000);
+        assert_eq!(describe(&first, None), "Part 1: 21 in 2.00µs");
+
+        let faster = answer(1, "21", 1_500);
+        assert_eq!(
+            describe(&faster, Some(&first)),
+            "Part 1: 21 in 1.50µs (-25.0%)"
+        );
+
+        let mut wrong = answer(1, "22", 2_000);
+        wrong.status = "fail".to_string();
+        wrong.expected = Some("21".to_string());
+        assert_eq!(
+            describe(&wrong, Some(&first)),
+            "Part 1: 22 in 2.00µs (+0.0%), was 21, expected 21"
+        );
+
+        let failed = Answer {
+            answer: None,
+            error: Some("line 3, column 1: expected a digit".to_string()),
+            ..first
+        };
+        assert_eq!(
+            describe(&failed, None),
+            "Part 1: line 3, column 1: expected a digit"
+        );
+    }
+
+    #[test]
+    fn test_answers_from_json() {
+        let json = r#"[{"year":2025,"day":7,"part":1,"answer":"21","answer_type":"u64",
+            "duration_ns":2000,"status":"pass","expected":"21","error":null}]"#;
+        let answers: Vec<Answer> = serde_json::from_str(json).unwrap();
+        assert_eq!(answers, vec![answer(1, "21", 2_000)]);
+    }
+}
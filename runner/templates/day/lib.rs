@@ -0,0 +1,62 @@
+use aoc_common::error::{Line, Result, lines};
+use aoc_common::solution::{Params, Solution};
+
+pub const SAMPLE: &str = include_str!("data-sample.txt");
+pub const ACTUAL: &str = include_str!("data-actual.txt");
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day_number}};
+    const SAMPLE: &'static str = SAMPLE;
+    const INPUT: &'static str = ACTUAL;
+
+    type Input<'a> = Vec<Line<'a>>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Line<'_>>> {
+        parse(input)
+    }
+
+    fn part_one(input: &Vec<Line<'_>>, _params: &Params) -> Result<u64> {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<Line<'_>>, _params: &Params) -> Result<u64> {
+        part_two(input)
+    }
+}
+
+#[allow(clippy::unnecessary_wraps)]
+pub fn parse(file: &str) -> Result<Vec<Line<'_>>> {
+    Ok(lines(file).collect())
+}
+
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_one(_lines: &[Line]) -> Result<u64> {
+    Ok(0)
+}
+
+#[allow(clippy::unnecessary_wraps)]
+pub fn part_two(_lines: &[Line]) -> Result<u64> {
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(SAMPLE).unwrap();
+        assert_eq!(part_one(&input).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(SAMPLE).unwrap();
+        assert_eq!(part_two(&input).unwrap(), 0);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_{{year}}_{{day}}::Day{{day}};
+
+fn main() -> ExitCode {
+    aoc_common::standalone::run(&Day{{day}}, include_str!("../../answers.toml"))
+}